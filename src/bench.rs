@@ -0,0 +1,244 @@
+use crate::solution::Part;
+use crate::{AdventError, AdventResult};
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::hint::black_box;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+pub struct BenchConfig {
+    pub warmup: usize,
+    pub samples: usize,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            warmup: 3,
+            samples: 10,
+        }
+    }
+}
+
+impl BenchConfig {
+    /// Runs `f` `warmup` times untimed, then times it `samples` times.
+    pub fn measure<T, F>(&self, mut f: F) -> AdventResult<Stats>
+    where
+        F: FnMut() -> AdventResult<T>,
+    {
+        for _ in 0..self.warmup {
+            black_box(f()?);
+        }
+        let mut samples = Vec::with_capacity(self.samples);
+        for _ in 0..self.samples.max(1) {
+            let start = Instant::now();
+            black_box(f()?);
+            samples.push(start.elapsed());
+        }
+        Ok(Stats::from_samples(&samples))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Stage {
+    Parse,
+    Part(Part),
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Stage::Parse => write!(f, "parse"),
+            Stage::Part(part) => write!(f, "part{}", part),
+        }
+    }
+}
+
+impl std::str::FromStr for Stage {
+    type Err = AdventError;
+
+    fn from_str(s: &str) -> AdventResult<Stage> {
+        match s {
+            "parse" => Ok(Stage::Parse),
+            "part1" => Ok(Stage::Part(Part::One)),
+            "part2" => Ok(Stage::Part(Part::Two)),
+            _ => Err(AdventError::InvalidValue),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub mean: Duration,
+    pub stddev: Duration,
+    pub min: Duration,
+    pub max: Duration,
+    pub samples: usize,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let n = samples.len().max(1) as f64;
+        let nanos: Vec<f64> = samples.iter().map(|d| d.as_nanos() as f64).collect();
+        let mean = nanos.iter().sum::<f64>() / n;
+        let variance = nanos.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / n;
+        Self {
+            mean: Duration::from_nanos(mean.round() as u64),
+            stddev: Duration::from_nanos(variance.sqrt().round() as u64),
+            min: samples.iter().min().copied().unwrap_or_default(),
+            max: samples.iter().max().copied().unwrap_or_default(),
+            samples: samples.len(),
+        }
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{:>10.3?} ± {:>10.3?}  [{:.3?} … {:.3?}]  {} runs",
+            self.mean, self.stddev, self.min, self.max, self.samples
+        )
+    }
+}
+
+pub struct DayBench {
+    pub day: u8,
+    pub stages: Vec<(Stage, Stats)>,
+}
+
+impl fmt::Display for DayBench {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (stage, stats) in self.stages.iter() {
+            writeln!(f, "Day {:02} {:<6} {}", self.day, stage, stats)?;
+        }
+        Ok(())
+    }
+}
+
+pub struct Regression {
+    pub day: u8,
+    pub stage: Stage,
+    pub baseline: Duration,
+    pub current: Duration,
+}
+
+impl fmt::Display for Regression {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let change = self.current.as_secs_f64() / self.baseline.as_secs_f64() - 1.0;
+        write!(
+            f,
+            "Day {:02} {}: {:.3?} -> {:.3?} (+{:.1}%)",
+            self.day,
+            self.stage,
+            self.baseline,
+            self.current,
+            100.0 * change
+        )
+    }
+}
+
+/// Mean timings per (day, stage), stored as CSV so runs can be compared over time.
+#[derive(Default)]
+pub struct Baseline {
+    means: HashMap<(u8, Stage), Duration>,
+}
+
+impl Baseline {
+    pub fn from_benches(benches: &[DayBench]) -> Self {
+        let means = benches
+            .iter()
+            .flat_map(|b| b.stages.iter().map(move |(s, st)| ((b.day, *s), st.mean)))
+            .collect();
+        Self { means }
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> AdventResult<Self> {
+        let data = fs::read_to_string(path)?;
+        let mut reader = csv::Reader::from_reader(data.as_bytes());
+        let mut means = HashMap::new();
+        for record in reader.records() {
+            let record = record?;
+            match (record.get(0), record.get(1), record.get(2)) {
+                (Some(day), Some(stage), Some(mean)) => {
+                    let mean = Duration::from_nanos(mean.parse()?);
+                    means.insert((day.parse()?, stage.parse()?), mean);
+                }
+                _ => return Err(AdventError::InvalidValue),
+            }
+        }
+        Ok(Self { means })
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> AdventResult<()> {
+        let mut writer = csv::Writer::from_path(path)?;
+        writer.write_record(["day", "stage", "mean_ns"])?;
+        let mut entries: Vec<_> = self.means.iter().collect();
+        entries.sort();
+        for ((day, stage), mean) in entries {
+            writer.write_record(&[
+                day.to_string(),
+                stage.to_string(),
+                mean.as_nanos().to_string(),
+            ])?;
+        }
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Stages whose mean got slower than the baseline by more than `threshold` (0.1 = 10%).
+    pub fn regressions(&self, benches: &[DayBench], threshold: f64) -> Vec<Regression> {
+        let mut regressions = Vec::new();
+        for bench in benches {
+            for &(stage, stats) in bench.stages.iter() {
+                if let Some(&baseline) = self.means.get(&(bench.day, stage)) {
+                    if stats.mean.as_secs_f64() > baseline.as_secs_f64() * (1.0 + threshold) {
+                        regressions.push(Regression {
+                            day: bench.day,
+                            stage,
+                            baseline,
+                            current: stats.mean,
+                        });
+                    }
+                }
+            }
+        }
+        regressions
+    }
+}
+
+#[test]
+fn test_stats() {
+    let samples: Vec<_> = [2, 4, 4, 4, 5, 5, 7, 9]
+        .iter()
+        .map(|&ms| Duration::from_millis(ms))
+        .collect();
+    let stats = Stats::from_samples(&samples);
+    assert_eq!(stats.mean, Duration::from_millis(5));
+    assert_eq!(stats.stddev, Duration::from_millis(2));
+    assert_eq!(stats.min, Duration::from_millis(2));
+    assert_eq!(stats.max, Duration::from_millis(9));
+}
+
+#[test]
+fn test_regressions() {
+    let stats = |ms| Stats::from_samples(&[Duration::from_millis(ms)]);
+    let baseline = Baseline::from_benches(&[DayBench {
+        day: 1,
+        stages: vec![
+            (Stage::Parse, stats(10)),
+            (Stage::Part(Part::One), stats(10)),
+        ],
+    }]);
+    let current = [DayBench {
+        day: 1,
+        stages: vec![
+            (Stage::Parse, stats(10)),
+            (Stage::Part(Part::One), stats(20)),
+        ],
+    }];
+    let regressions = baseline.regressions(&current, 0.1);
+    assert_eq!(regressions.len(), 1);
+    assert_eq!(regressions[0].stage, Stage::Part(Part::One));
+}
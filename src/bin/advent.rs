@@ -0,0 +1,291 @@
+use advent2019::bench::{Baseline, BenchConfig, DayBench};
+use advent2019::days;
+use advent2019::render::Recorder;
+use advent2019::report::Report;
+use advent2019::solution::{Auxiliary, DayReport, Params, Part};
+use advent2019::tui;
+use advent2019::{get_raw_input, AdventResult};
+use std::env;
+use std::process::ExitCode;
+
+const USAGE: &str = "Usage:
+  advent <day|all> [--part <1|2>] [--json] [--param KEY=VALUE]...
+  advent bench <day|all> [--part <1|2>] [--warmup N] [--samples N]
+                         [--save-baseline FILE] [--baseline FILE] [--threshold PCT]
+  advent record <day|all> [--out PATH] [--scale N] [--every N] [--delay CS]
+  advent play <day>
+
+record writes an animated GIF when PATH ends in .gif, numbered PNGs into the PATH directory
+otherwise. `{day}` in PATH is replaced by the day number (default: day{day}.gif).
+
+play runs an interactive Intcode program (days 13, 17, 21 and 25) in the terminal.
+
+--param overrides a constant from the puzzle text, for any command:
+  day 8:  width=25 height=6 palette=000000,ffffff,- (rrggbb colour per digit, - for transparent)
+  day 10: station=<x,y> (default: the best asteroid) start=U (U/R/D/L, arrows or x,y)
+          rotation=clockwise (or counterclockwise) nth=200
+  day 12: steps=1000
+  day 22: deck_size=10007 card=2019 big_deck_size=119315717514047
+          shuffle_count=101741582076661 position=2020
+  day 25: route=<commands separated by commas> replays a known route instead of exploring";
+
+struct RecordArgs {
+    out: String,
+    scale: usize,
+    every: usize,
+    // Hundredths of a second between frames
+    delay: u16,
+}
+
+impl Default for RecordArgs {
+    fn default() -> Self {
+        Self {
+            out: "day{day}.gif".to_string(),
+            scale: 4,
+            every: 1,
+            delay: 5,
+        }
+    }
+}
+
+struct BenchArgs {
+    config: BenchConfig,
+    save_baseline: Option<String>,
+    baseline: Option<String>,
+    threshold: f64,
+}
+
+impl Default for BenchArgs {
+    fn default() -> Self {
+        Self {
+            config: BenchConfig::default(),
+            save_baseline: None,
+            baseline: None,
+            // Flag stages more than 10% slower than the baseline
+            threshold: 0.1,
+        }
+    }
+}
+
+struct Args {
+    days: Vec<u8>,
+    parts: Vec<Part>,
+    bench: Option<BenchArgs>,
+    record: Option<RecordArgs>,
+    play: bool,
+    json: bool,
+    params: Params,
+}
+
+fn parse_args(args: &[String]) -> Option<Args> {
+    let mut days = None;
+    let mut parts = Part::BOTH.to_vec();
+    let mut bench: Option<BenchArgs> = None;
+    let mut record: Option<RecordArgs> = None;
+    let mut play = false;
+    let mut json = false;
+    let mut params = Params::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" | "-p" => {
+                parts = match args.next()?.as_str() {
+                    "1" => vec![Part::One],
+                    "2" => vec![Part::Two],
+                    _ => return None,
+                }
+            }
+            "--json" => json = true,
+            "--param" => params.insert_pair(args.next()?).ok()?,
+            "bench" => bench = Some(BenchArgs::default()),
+            "--warmup" => bench.as_mut()?.config.warmup = args.next()?.parse().ok()?,
+            "--samples" => bench.as_mut()?.config.samples = args.next()?.parse().ok()?,
+            "--save-baseline" => bench.as_mut()?.save_baseline = Some(args.next()?.clone()),
+            "--baseline" => bench.as_mut()?.baseline = Some(args.next()?.clone()),
+            "--threshold" => bench.as_mut()?.threshold = args.next()?.parse::<f64>().ok()? / 100.0,
+            "record" => record = Some(RecordArgs::default()),
+            "--out" => record.as_mut()?.out = args.next()?.clone(),
+            "--scale" => record.as_mut()?.scale = args.next()?.parse().ok()?,
+            "--every" => record.as_mut()?.every = args.next()?.parse().ok()?,
+            "--delay" => record.as_mut()?.delay = args.next()?.parse().ok()?,
+            "play" => play = true,
+            "all" => days = Some(days::ALL.to_vec()),
+            day => days = Some(vec![day.parse().ok()?]),
+        }
+    }
+    Some(Args {
+        days: days?,
+        parts,
+        bench,
+        record,
+        play,
+        json,
+        params,
+    })
+}
+
+fn run(day: u8, args: &Args) -> AdventResult<(DayReport, Auxiliary)> {
+    let runner = days::runner_with_params(day, &args.params)?;
+    let input = get_raw_input(day)?;
+    let report = runner.run(&input, &args.parts)?;
+    let auxiliary = if args.json {
+        runner.auxiliary(&input)?
+    } else {
+        Auxiliary::new()
+    };
+    Ok((report, auxiliary))
+}
+
+fn bench(day: u8, parts: &[Part], config: &BenchConfig, params: &Params) -> AdventResult<DayBench> {
+    let runner = days::runner_with_params(day, params)?;
+    let input = get_raw_input(day)?;
+    runner.bench(&input, parts, config)
+}
+
+fn record(day: u8, record_args: &RecordArgs, params: &Params) -> AdventResult<Option<String>> {
+    let runner = days::runner_with_params(day, params)?;
+    let input = get_raw_input(day)?;
+    let mut recorder = Recorder::new(record_args.scale, record_args.every);
+    runner.record(&input, &mut recorder)?;
+    let animation = recorder.into_animation();
+    if animation.is_empty() {
+        return Ok(None);
+    }
+    let path = record_args.out.replace("{day}", &format!("{:02}", day));
+    animation.save(&path, record_args.delay)?;
+    Ok(Some(format!("{} frames to {}", animation.len(), path)))
+}
+
+fn run_recordings(args: &Args, record_args: &RecordArgs) -> bool {
+    let mut failed = false;
+    for &day in args.days.iter() {
+        match record(day, record_args, &args.params) {
+            Ok(Some(summary)) => println!("Day {:02}: {}", day, summary),
+            Ok(None) => {
+                eprintln!("Day {:02} has nothing to record", day);
+                failed = true;
+            }
+            Err(err) => {
+                eprintln!("Day {:02} failed: {}", day, err);
+                failed = true;
+            }
+        }
+    }
+    failed
+}
+
+fn play(day: u8, params: &Params) -> AdventResult<bool> {
+    let runner = days::runner_with_params(day, params)?;
+    let input = get_raw_input(day)?;
+    match runner.console(&input)? {
+        Some(mut session) => tui::run(&mut session).map(|_| true),
+        None => Ok(false),
+    }
+}
+
+fn run_play(args: &Args) -> bool {
+    match args.days.as_slice() {
+        &[day] => match play(day, &args.params) {
+            Ok(true) => false,
+            Ok(false) => {
+                eprintln!("Day {:02} has no interactive program", day);
+                true
+            }
+            Err(err) => {
+                eprintln!("Day {:02} failed: {}", day, err);
+                true
+            }
+        },
+        _ => {
+            eprintln!("{}", USAGE);
+            true
+        }
+    }
+}
+
+fn run_benches(args: &Args, bench_args: &BenchArgs) -> bool {
+    let mut failed = false;
+    let mut benches = Vec::new();
+    for &day in args.days.iter() {
+        match bench(day, &args.parts, &bench_args.config, &args.params) {
+            Ok(day_bench) => {
+                print!("{}", day_bench);
+                benches.push(day_bench);
+            }
+            Err(err) => {
+                eprintln!("Day {:02} failed: {}", day, err);
+                failed = true;
+            }
+        }
+    }
+
+    if let Some(path) = &bench_args.baseline {
+        match Baseline::load(path) {
+            Ok(baseline) => {
+                let regressions = baseline.regressions(&benches, bench_args.threshold);
+                for regression in regressions.iter() {
+                    println!("Regression: {}", regression);
+                }
+                failed |= !regressions.is_empty();
+            }
+            Err(err) => {
+                eprintln!("Could not load baseline {}: {}", path, err);
+                failed = true;
+            }
+        }
+    }
+
+    if let Some(path) = &bench_args.save_baseline {
+        if let Err(err) = Baseline::from_benches(&benches).save(path) {
+            eprintln!("Could not save baseline {}: {}", path, err);
+            failed = true;
+        }
+    }
+    failed
+}
+
+fn run_solutions(args: &Args) -> bool {
+    let mut failed = false;
+    let mut json = Report::new();
+    for &day in args.days.iter() {
+        match run(day, args) {
+            Ok((report, auxiliary)) if args.json => json.push(report, auxiliary),
+            Ok((report, _)) => report.answers.iter().for_each(|a| println!("{}", a)),
+            Err(err) => {
+                if args.json {
+                    json.push_error(day, &err);
+                }
+                eprintln!("Day {:02} failed: {}", day, err);
+                failed = true;
+            }
+        }
+    }
+    if args.json {
+        println!("{}", json.to_json());
+    }
+    failed
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let args = match parse_args(&args) {
+        Some(args) => args,
+        None => {
+            eprintln!("{}", USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let failed = match (&args.bench, &args.record) {
+        _ if args.play => run_play(&args),
+        (Some(bench_args), _) => run_benches(&args, bench_args),
+        (None, Some(record_args)) => run_recordings(&args, record_args),
+        (None, None) => run_solutions(&args),
+    };
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
@@ -0,0 +1,198 @@
+use crate::solution::{Auxiliary, Solution};
+use crate::{parse_input, AdventError, AdventResult};
+use std::cmp::Reverse;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    type Input = Vec<u32>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> AdventResult<Vec<u32>> {
+        parse_input::<u32>(input)?.first_column()
+    }
+
+    fn part1(masses: &Vec<u32>) -> AdventResult<u32> {
+        FuelReport::new(masses, RocketEquation::default())?.fuel()
+    }
+
+    fn part2(masses: &Vec<u32>) -> AdventResult<u32> {
+        FuelReport::new(masses, RocketEquation::default())?.total()
+    }
+
+    fn auxiliary(masses: &Vec<u32>) -> AdventResult<Auxiliary> {
+        let report = FuelReport::new(masses, RocketEquation::default())?;
+        let dominant: AdventResult<Vec<_>> = report
+            .dominant(3)
+            .iter()
+            .map(|module| Ok(serde_json::json!([module.mass, module.total()?])))
+            .collect();
+        let mut auxiliary = Auxiliary::new();
+        auxiliary.insert("dominant_modules".to_string(), dominant?.into());
+        Ok(auxiliary)
+    }
+}
+
+/// Fuel needed to launch a mass: `mass / divisor - subtrahend`, rounding down.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RocketEquation {
+    divisor: u32,
+    subtrahend: u32,
+}
+
+impl Default for RocketEquation {
+    fn default() -> Self {
+        Self {
+            divisor: 3,
+            subtrahend: 2,
+        }
+    }
+}
+
+impl RocketEquation {
+    /// Fails unless fuel is always lighter than what it launches, so fuel for fuel runs out.
+    pub fn new(divisor: u32, subtrahend: u32) -> AdventResult<Self> {
+        if divisor == 0 || (divisor == 1 && subtrahend == 0) {
+            return Err(AdventError::InvalidValue);
+        }
+        Ok(Self {
+            divisor,
+            subtrahend,
+        })
+    }
+
+    /// Fails for masses too light to give a non-negative amount of fuel.
+    pub fn fuel(&self, mass: u32) -> AdventResult<u32> {
+        (mass / self.divisor)
+            .checked_sub(self.subtrahend)
+            .ok_or(AdventError::InvalidValue)
+    }
+
+    /// Fuel for `mass`, then fuel for that fuel and so on, until the fuel needs none. Negative
+    /// amounts of fuel for fuel count as none, as in the puzzle.
+    pub fn fuel_chain(&self, mass: u32) -> AdventResult<Vec<u32>> {
+        let mut chain = vec![self.fuel(mass)?];
+        while let Some(fuel) = chain.last().and_then(|&fuel| self.fuel(fuel).ok()) {
+            if fuel == 0 {
+                break;
+            }
+            chain.push(fuel);
+        }
+        Ok(chain)
+    }
+}
+
+/// A module and the fuel it needs: `chain[0]` for its own mass, the rest for the fuel before.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModuleFuel {
+    pub mass: u32,
+    pub chain: Vec<u32>,
+}
+
+impl ModuleFuel {
+    pub fn fuel(&self) -> u32 {
+        self.chain[0]
+    }
+
+    /// Fuel for the module and for all its fuel.
+    pub fn total(&self) -> AdventResult<u32> {
+        checked_sum(self.chain.iter().copied())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FuelReport {
+    pub modules: Vec<ModuleFuel>,
+}
+
+impl FuelReport {
+    /// Fails with the line of the first mass too light to need fuel.
+    pub fn new(masses: &[u32], equation: RocketEquation) -> AdventResult<Self> {
+        let modules = masses
+            .iter()
+            .zip(1..)
+            .map(|(&mass, line)| {
+                let chain = equation
+                    .fuel_chain(mass)
+                    .map_err(|err| AdventError::InvalidField {
+                        line,
+                        field: 1,
+                        text: mass.to_string(),
+                        source: Box::new(err),
+                    })?;
+                Ok(ModuleFuel { mass, chain })
+            })
+            .collect::<AdventResult<_>>()?;
+        Ok(Self { modules })
+    }
+
+    /// Fuel for the modules alone (part 1).
+    pub fn fuel(&self) -> AdventResult<u32> {
+        checked_sum(self.modules.iter().map(ModuleFuel::fuel))
+    }
+
+    /// Fuel for the modules and their fuel (part 2).
+    pub fn total(&self) -> AdventResult<u32> {
+        self.modules.iter().try_fold(0u32, |sum, module| {
+            sum.checked_add(module.total()?)
+                .ok_or(AdventError::Overflow)
+        })
+    }
+
+    /// The `n` modules needing the most fuel in total, heaviest first.
+    pub fn dominant(&self, n: usize) -> Vec<&ModuleFuel> {
+        // Summed as u64 so modules still rank when their total overflows
+        let total = |module: &ModuleFuel| module.chain.iter().map(|&f| u64::from(f)).sum::<u64>();
+        let mut modules: Vec<&ModuleFuel> = self.modules.iter().collect();
+        modules.sort_by_key(|&module| Reverse(total(module)));
+        modules.truncate(n);
+        modules
+    }
+}
+
+fn checked_sum<I: Iterator<Item = u32>>(mut values: I) -> AdventResult<u32> {
+    values.try_fold(0u32, |sum, value| {
+        sum.checked_add(value).ok_or(AdventError::Overflow)
+    })
+}
+
+#[test]
+fn test_day01_fuel_chain() {
+    let equation = RocketEquation::default();
+    assert_eq!(equation.fuel_chain(14).unwrap(), vec![2]);
+    assert_eq!(
+        equation.fuel_chain(1969).unwrap(),
+        vec![654, 216, 70, 21, 5]
+    );
+    assert_eq!(equation.fuel_chain(6).unwrap(), vec![0]);
+    assert!(matches!(
+        equation.fuel_chain(5),
+        Err(AdventError::InvalidValue)
+    ));
+}
+
+#[test]
+fn test_day01_report() {
+    let report = FuelReport::new(&[12, 14, 1969, 100756], RocketEquation::default()).unwrap();
+    assert_eq!(report.fuel().unwrap(), 34241);
+    assert_eq!(report.total().unwrap(), 51316);
+    let dominant: Vec<u32> = report.dominant(2).iter().map(|m| m.mass).collect();
+    assert_eq!(dominant, vec![100756, 1969]);
+
+    let report = FuelReport::new(&[u32::MAX; 4], RocketEquation::default()).unwrap();
+    assert!(matches!(report.fuel(), Err(AdventError::Overflow)));
+    assert!(matches!(report.total(), Err(AdventError::Overflow)));
+
+    let light = FuelReport::new(&[12, 5], RocketEquation::default()).unwrap_err();
+    assert_eq!(
+        light.to_string(),
+        "Invalid field 1 on line 2: \"5\" (Invalid value)"
+    );
+
+    let equation = RocketEquation::new(10, 1).unwrap();
+    assert_eq!(equation.fuel_chain(1000).unwrap(), vec![99, 8]);
+    assert!(RocketEquation::new(0, 2).is_err());
+    assert!(RocketEquation::new(1, 0).is_err());
+}
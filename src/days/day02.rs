@@ -0,0 +1,260 @@
+use crate::intcode::IntCode;
+use crate::parallel::Pool;
+use crate::solution::Solution;
+use crate::{parse_input, AdventError, AdventResult};
+use std::convert::TryFrom;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    type Input = Vec<isize>;
+    type Part1 = isize;
+    type Part2 = isize;
+
+    fn parse(input: &str) -> AdventResult<Vec<isize>> {
+        parse_input::<isize>(input)?.first_row()
+    }
+
+    fn part1(program: &Vec<isize>) -> AdventResult<isize> {
+        solve_part1(program)
+    }
+
+    fn part2(program: &Vec<isize>) -> AdventResult<isize> {
+        solve_part2(program)
+    }
+}
+
+const TARGET: isize = 19_690_720;
+
+pub fn solve_part1(program: &[isize]) -> AdventResult<isize> {
+    // "1202 program alarm" state
+    run_with(program, 12, 2)
+}
+
+/// Inverts the program's output when it is linear in the noun and the verb, searches otherwise.
+pub fn solve_part2(program: &[isize]) -> AdventResult<isize> {
+    let (noun, verb) = match LinearOutput::evaluate(program)? {
+        Some(output) => output.invert(TARGET)?,
+        None => brute_force(program, TARGET)?,
+    }
+    .ok_or(AdventError::InvalidValue)?;
+    Ok(100 * noun + verb)
+}
+
+/// Position 0 after running `program` with `noun` and `verb` in positions 1 and 2.
+pub fn run_with(program: &[isize], noun: isize, verb: isize) -> AdventResult<isize> {
+    if program.len() < 3 {
+        return Err(AdventError::InvalidValue);
+    }
+    let mut program = program.to_vec();
+    program[1] = noun;
+    program[2] = verb;
+    let mut intcode = IntCode::new(&program);
+    intcode.try_run_till_halt(&[])?;
+    Ok(intcode.peek(0))
+}
+
+/// Every noun and verb pair in turn, the first one giving `target` wins.
+fn brute_force(program: &[isize], target: isize) -> AdventResult<Option<(isize, isize)>> {
+    let pairs: Vec<(isize, isize)> = (0..=99)
+        .flat_map(|noun| (0..=99).map(move |verb| (noun, verb)))
+        .collect();
+    Pool::default()
+        .find_map_first(&pairs, |&(noun, verb)| {
+            match run_with(program, noun, verb) {
+                Ok(output) if output == target => Some(Ok((noun, verb))),
+                Ok(_) => None,
+                Err(err) => Some(Err(err)),
+            }
+        })
+        .transpose()
+}
+
+/// `constant + noun * noun_factor + verb * verb_factor`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LinearOutput {
+    constant: isize,
+    noun: isize,
+    verb: isize,
+}
+
+impl LinearOutput {
+    fn constant(constant: isize) -> Self {
+        Self {
+            constant,
+            noun: 0,
+            verb: 0,
+        }
+    }
+
+    fn as_constant(&self) -> Option<isize> {
+        (self.noun == 0 && self.verb == 0).then_some(self.constant)
+    }
+
+    /// Runs the add/mul program on expressions instead of numbers, with the noun and verb left
+    /// as unknowns. Values read through an unknown address are unknown themselves, which is fine
+    /// as long as they never reach position 0. `None` when the output is not linear or the program
+    /// uses anything but additions and multiplications on known addresses.
+    pub fn evaluate(program: &[isize]) -> AdventResult<Option<Self>> {
+        if program.len() < 3 {
+            return Ok(None);
+        }
+        let mut memory: Vec<Option<LinearOutput>> = program
+            .iter()
+            .map(|&value| Some(Self::constant(value)))
+            .collect();
+        memory[1] = Some(Self {
+            constant: 0,
+            noun: 1,
+            verb: 0,
+        });
+        memory[2] = Some(Self {
+            constant: 0,
+            noun: 0,
+            verb: 1,
+        });
+
+        let known = |memory: &[Option<LinearOutput>], address: usize| -> Option<usize> {
+            let value = memory.get(address).copied().flatten()?.as_constant()?;
+            usize::try_from(value).ok()
+        };
+        let mut ip = 0;
+        loop {
+            let Some(op) = known(&memory, ip) else {
+                return Ok(None);
+            };
+            if op == 99 {
+                return Ok(memory[0]);
+            }
+            let read = |shift: usize| {
+                let address = known(&memory, ip + shift)?;
+                memory.get(address).copied().flatten()
+            };
+            let result = match (op, read(1).zip(read(2))) {
+                (1, Some((a, b))) => Some(a.checked_add(b)?),
+                (2, Some((a, b))) => a.checked_mul(b)?,
+                (1, None) | (2, None) => None,
+                _ => return Ok(None),
+            };
+            let Some(cell) = known(&memory, ip + 3).and_then(|target| memory.get_mut(target))
+            else {
+                return Ok(None);
+            };
+            *cell = result;
+            ip += 4;
+        }
+    }
+
+    /// The first noun and verb, nouns first, both in `0..=99`, for which the output is `target`.
+    pub fn invert(&self, target: isize) -> AdventResult<Option<(isize, isize)>> {
+        for noun in 0..=99 {
+            let rest = self
+                .noun
+                .checked_mul(noun)
+                .and_then(|noun| target.checked_sub(self.constant)?.checked_sub(noun))
+                .ok_or(AdventError::Overflow)?;
+            let verb = match self.verb {
+                0 if rest == 0 => 0,
+                0 => continue,
+                factor if rest % factor == 0 => rest / factor,
+                _ => continue,
+            };
+            if (0..=99).contains(&verb) {
+                return Ok(Some((noun, verb)));
+            }
+        }
+        Ok(None)
+    }
+
+    pub fn checked_add(self, other: Self) -> AdventResult<Self> {
+        let add = |a: isize, b: isize| a.checked_add(b).ok_or(AdventError::Overflow);
+        Ok(Self {
+            constant: add(self.constant, other.constant)?,
+            noun: add(self.noun, other.noun)?,
+            verb: add(self.verb, other.verb)?,
+        })
+    }
+
+    /// `None` when both sides depend on the noun or verb.
+    pub fn checked_mul(self, other: Self) -> AdventResult<Option<Self>> {
+        let (factor, linear) = match (self.as_constant(), other.as_constant()) {
+            (Some(factor), _) => (factor, other),
+            (_, Some(factor)) => (factor, self),
+            _ => return Ok(None),
+        };
+        let mul = |a: isize| a.checked_mul(factor).ok_or(AdventError::Overflow);
+        Ok(Some(Self {
+            constant: mul(linear.constant)?,
+            noun: mul(linear.noun)?,
+            verb: mul(linear.verb)?,
+        }))
+    }
+}
+
+#[test]
+fn test_program() {
+    let program = [1, 9, 10, 3, 2, 3, 11, 0, 99, 30, 40, 50];
+    let mut intcode = IntCode::new(&program);
+    intcode.run_till_halt(&[]);
+    assert_eq!(intcode.peek(0), 3500);
+    assert_eq!(intcode.peek(3), 70);
+}
+
+#[test]
+fn test_day02_linear_output() {
+    // position 0 = noun * 3 + verb + 5
+    let program = [
+        1, 0, 0, 3, 2, 1, 17, 19, 1, 19, 2, 19, 1, 19, 18, 0, 99, 3, 5, 0,
+    ];
+    let output = LinearOutput::evaluate(&program).unwrap().unwrap();
+    for (noun, verb) in [(0, 0), (12, 2), (99, 99)] {
+        assert_eq!(
+            output.constant + output.noun * noun + output.verb * verb,
+            run_with(&program, noun, verb).unwrap()
+        );
+    }
+    // Nouns come first: 12 and 97 is found before 42 and 7
+    assert_eq!(output.invert(3 * 42 + 7 + 5).unwrap(), Some((12, 97)));
+    assert_eq!(
+        output.invert(3 * 42 + 7 + 5).unwrap(),
+        brute_force(&program, 3 * 42 + 7 + 5).unwrap()
+    );
+    assert_eq!(output.invert(1000).unwrap(), None);
+
+    // position 0 = noun * verb
+    let program = [2, 0, 0, 0, 99];
+    assert_eq!(LinearOutput::evaluate(&program).unwrap(), None);
+}
+
+#[test]
+fn test_day02_inversion_matches_brute_force() -> AdventResult<()> {
+    let program = crate::get_input::<isize>(2)?.first_row()?;
+    let output = LinearOutput::evaluate(&program)?.ok_or(AdventError::InvalidValue)?;
+    assert_eq!(output.invert(TARGET)?, brute_force(&program, TARGET)?);
+    Ok(())
+}
+
+#[test]
+fn test_day02_errors() {
+    // Waits for input it is never given
+    let program = [3, 7, 7, 0, 99];
+    assert!(matches!(
+        run_with(&program, 0, 0),
+        Err(AdventError::InvalidValue)
+    ));
+    // Unknown op code, found by the brute force search since evaluation gives up on it
+    let program = [1, 0, 0, 0, 42, 0, 0, 0];
+    assert_eq!(LinearOutput::evaluate(&program).unwrap(), None);
+    assert!(matches!(
+        solve_part2(&program),
+        Err(AdventError::InvalidValue)
+    ));
+
+    // isize::MAX * 2
+    let program = [1, 0, 0, 3, 2, 9, 10, 0, 99, isize::MAX, 2];
+    assert!(matches!(
+        LinearOutput::evaluate(&program),
+        Err(AdventError::Overflow)
+    ));
+}
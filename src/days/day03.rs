@@ -0,0 +1,323 @@
+use crate::geometry::{Direction, Point};
+use crate::solution::Solution;
+use crate::{parse_input, AdventError, AdventResult};
+use std::collections::{BTreeMap, HashMap};
+use std::convert::TryFrom;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    type Input = Vec<Vec<Instruction>>;
+    type Part1 = i64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> AdventResult<Self::Input> {
+        load_input(input)
+    }
+
+    fn part1(wires: &Self::Input) -> AdventResult<i64> {
+        closest_crossing(wires)
+    }
+
+    fn part2(wires: &Self::Input) -> AdventResult<u64> {
+        cheapest_crossing(wires)
+    }
+}
+
+type Coord = Point<i64>;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Instruction {
+    direction: Direction,
+    length: u64,
+}
+
+pub fn parse_instruction<T: AsRef<str>>(direction: T) -> AdventResult<Instruction> {
+    let (dir, len) = direction.as_ref().split_at(1);
+    let dir = Direction::try_from(dir.chars().next().ok_or(AdventError::InvalidValue)?)?;
+    let len = len.parse::<u64>()?;
+    Ok(Instruction {
+        direction: dir,
+        length: len,
+    })
+}
+
+pub fn parse_instructions<T: AsRef<str>>(input: &[T]) -> AdventResult<Vec<Instruction>> {
+    input.iter().map(parse_instruction).collect()
+}
+
+pub fn load_input(input: &str) -> AdventResult<Vec<Vec<Instruction>>> {
+    let wires = parse_input::<String>(input)?
+        .get_data()
+        .iter()
+        .map(|wire| parse_instructions(wire))
+        .collect::<AdventResult<Vec<_>>>()?;
+    if wires.len() < 2 {
+        return Err(AdventError::EmptyInput);
+    }
+    Ok(wires)
+}
+
+/// A straight stretch of wire, `steps` along the wire from the central port at `start`.
+#[derive(Debug, Clone, Copy)]
+struct Segment {
+    wire: usize,
+    start: Coord,
+    end: Coord,
+    steps: u64,
+}
+
+impl Segment {
+    fn is_horizontal(&self) -> bool {
+        self.start.y == self.end.y
+    }
+
+    /// Bounds along the segment's own axis.
+    fn range(&self) -> (i64, i64) {
+        let (a, b) = if self.is_horizontal() {
+            (self.start.x, self.end.x)
+        } else {
+            (self.start.y, self.end.y)
+        };
+        (a.min(b), a.max(b))
+    }
+
+    fn steps_to(&self, point: Coord) -> u64 {
+        self.steps + point.manhattan(self.start) as u64
+    }
+}
+
+fn segments(wire: usize, instructions: &[Instruction]) -> Vec<Segment> {
+    let mut start = Coord::default();
+    let mut steps = 0;
+    instructions
+        .iter()
+        // Empty moves are already covered by their neighbours
+        .filter(|instruction| instruction.length > 0)
+        .map(|instruction| {
+            let end = start + instruction.direction.offset() * instruction.length as i64;
+            let segment = Segment {
+                wire,
+                start,
+                end,
+                steps,
+            };
+            start = end;
+            steps += instruction.length;
+            segment
+        })
+        .collect()
+}
+
+/// A point where at least two wires meet, with the fewest steps each of them takes to get there.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Crossing {
+    pub point: Coord,
+    pub distance: i64,
+    /// `(wire, steps)`, by wire
+    pub steps: Vec<(usize, u64)>,
+}
+
+impl Crossing {
+    pub fn total_steps(&self) -> u64 {
+        self.steps.iter().map(|(_, steps)| steps).sum()
+    }
+}
+
+#[derive(Default)]
+struct Crossings(HashMap<Coord, BTreeMap<usize, u64>>);
+
+impl Crossings {
+    fn add(&mut self, point: Coord, segments: &[&Segment]) {
+        let wires = self.0.entry(point).or_default();
+        for segment in segments {
+            let steps = wires.entry(segment.wire).or_insert(u64::MAX);
+            *steps = (*steps).min(segment.steps_to(point));
+        }
+    }
+}
+
+/// Every point other than the central port where segments of the two different wires cross or
+/// overlap, closest to the port first.
+///
+/// Perpendicular segments are matched by sweeping a vertical line from left to right over the
+/// horizontal segments it currently crosses, kept by height. Segments lying on the same line are
+/// matched separately, every point they share being a crossing.
+pub fn crossings(wires: &[Vec<Instruction>]) -> Vec<Crossing> {
+    let segments: Vec<Segment> = wires
+        .iter()
+        .enumerate()
+        .flat_map(|(wire, instructions)| segments(wire, instructions))
+        .collect();
+    let mut crossings = Crossings::default();
+
+    // Horizontal segments open before and close after the vertical ones at the same x
+    let mut events: Vec<(i64, u8, usize)> = Vec::new();
+    for (i, segment) in segments.iter().enumerate() {
+        let (lo, hi) = segment.range();
+        if segment.is_horizontal() {
+            events.push((lo, 0, i));
+            events.push((hi, 2, i));
+        } else {
+            events.push((segment.start.x, 1, i));
+        }
+    }
+    events.sort_unstable();
+    let mut active: BTreeMap<i64, Vec<usize>> = BTreeMap::new();
+    for (x, event, i) in events {
+        let segment = &segments[i];
+        match event {
+            0 => active.entry(segment.start.y).or_default().push(i),
+            2 => active
+                .get_mut(&segment.start.y)
+                .expect("open segment")
+                .retain(|&j| j != i),
+            _ => {
+                let (lo, hi) = segment.range();
+                for (&y, horizontals) in active.range(lo..=hi) {
+                    for other in horizontals.iter().map(|&j| &segments[j]) {
+                        if other.wire != segment.wire {
+                            crossings.add(Coord::new(x, y), &[segment, other]);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    let mut lines: HashMap<(bool, i64), Vec<&Segment>> = HashMap::new();
+    for segment in segments.iter() {
+        let line = if segment.is_horizontal() {
+            segment.start.y
+        } else {
+            segment.start.x
+        };
+        lines
+            .entry((segment.is_horizontal(), line))
+            .or_default()
+            .push(segment);
+    }
+    for ((horizontal, line), mut collinear) in lines {
+        collinear.sort_unstable_by_key(|segment| segment.range());
+        for (i, segment) in collinear.iter().enumerate() {
+            let (_, hi) = segment.range();
+            for other in collinear[i + 1..].iter() {
+                let (other_lo, other_hi) = other.range();
+                if other_lo > hi {
+                    break;
+                }
+                if other.wire == segment.wire {
+                    continue;
+                }
+                for along in other_lo..=hi.min(other_hi) {
+                    let point = if horizontal {
+                        Coord::new(along, line)
+                    } else {
+                        Coord::new(line, along)
+                    };
+                    crossings.add(point, &[segment, other]);
+                }
+            }
+        }
+    }
+
+    let mut crossings: Vec<Crossing> = crossings
+        .0
+        .into_iter()
+        .filter(|(point, _)| *point != Coord::default())
+        .map(|(point, wires)| Crossing {
+            point,
+            distance: point.manhattan(Coord::default()),
+            steps: wires.into_iter().collect(),
+        })
+        .collect();
+    crossings
+        .sort_unstable_by_key(|crossing| (crossing.distance, crossing.point.x, crossing.point.y));
+    crossings
+}
+
+pub fn closest_crossing(wires: &[Vec<Instruction>]) -> AdventResult<i64> {
+    crossings(wires)
+        .iter()
+        .map(|crossing| crossing.distance)
+        .min()
+        .ok_or(AdventError::InvalidValue)
+}
+
+pub fn cheapest_crossing(wires: &[Vec<Instruction>]) -> AdventResult<u64> {
+    crossings(wires)
+        .iter()
+        .map(Crossing::total_steps)
+        .min()
+        .ok_or(AdventError::InvalidValue)
+}
+
+#[test]
+fn test_case_0() {
+    let wire1 = parse_instructions(&["R8", "U5", "L5", "D3"]).unwrap();
+    let wire2 = parse_instructions(&["U7", "R6", "D4", "L4"]).unwrap();
+    assert_eq!(
+        6,
+        closest_crossing(&[wire1.clone(), wire2.clone()]).unwrap()
+    );
+    assert_eq!(30, cheapest_crossing(&[wire1, wire2]).unwrap());
+}
+
+#[test]
+fn test_case_1() {
+    let wire1 = parse_instructions(&["R75", "D30", "R83", "U83", "L12", "D49", "R71", "U7", "L72"])
+        .unwrap();
+    let wire2 =
+        parse_instructions(&["U62", "R66", "U55", "R34", "D71", "R55", "D58", "R83"]).unwrap();
+    assert_eq!(
+        159,
+        closest_crossing(&[wire1.clone(), wire2.clone()]).unwrap()
+    );
+    assert_eq!(610, cheapest_crossing(&[wire1, wire2]).unwrap());
+}
+
+#[test]
+fn test_case_2() {
+    let wire1 = parse_instructions(&[
+        "R98", "U47", "R26", "D63", "R33", "U87", "L62", "D20", "R33", "U53", "R51",
+    ])
+    .unwrap();
+    let wire2 = parse_instructions(&[
+        "U98", "R91", "D20", "R16", "D67", "R40", "U7", "R15", "U6", "R7",
+    ])
+    .unwrap();
+    assert_eq!(
+        135,
+        closest_crossing(&[wire1.clone(), wire2.clone()]).unwrap()
+    );
+    assert_eq!(410, cheapest_crossing(&[wire1, wire2]).unwrap());
+}
+
+#[test]
+fn test_day03_collinear_overlap() {
+    // Both wires run along y = 0 from x = 2 to x = 4
+    let wire1 = parse_instructions(&["R4", "U2"]).unwrap();
+    let wire2 = parse_instructions(&["U1", "R2", "D1", "R5"]).unwrap();
+    let points: Vec<(i64, i64)> = crossings(&[wire1, wire2])
+        .iter()
+        .map(|crossing| crossing.point.into())
+        .collect();
+    assert_eq!(points, vec![(2, 0), (3, 0), (4, 0)]);
+}
+
+#[test]
+fn test_day03_three_wires() {
+    let wire1 = parse_instructions(&["R8", "U5", "L5", "D3"]).unwrap();
+    let wire2 = parse_instructions(&["U7", "R6", "D4", "L4"]).unwrap();
+    let wire3 = parse_instructions(&["U3", "R10"]).unwrap();
+    // Up is towards negative y
+    let crossings = crossings(&[wire1, wire2, wire3]);
+    let crossing = crossings
+        .iter()
+        .find(|crossing| crossing.point == Coord::new(3, -3))
+        .unwrap();
+    assert_eq!(crossing.distance, 6);
+    assert_eq!(crossing.steps, vec![(0, 20), (1, 20), (2, 6)]);
+    assert_eq!(crossing.total_steps(), 46);
+}
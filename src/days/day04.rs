@@ -0,0 +1,557 @@
+use crate::solution::Solution;
+use crate::{parse_input_with_params, AdventError, AdventResult};
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::{error, fmt};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    type Input = (u64, u64);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> AdventResult<(u64, u64)> {
+        let input = parse_input_with_params::<u64>(input, false, '-')?.first_row()?;
+        match input[..] {
+            [lowerbound, upperbound] => Ok((lowerbound, upperbound)),
+            _ => Err(AdventError::InvalidValue),
+        }
+    }
+
+    fn part1(&(lowerbound, upperbound): &(u64, u64)) -> AdventResult<usize> {
+        solve_part1(lowerbound, upperbound)
+    }
+
+    fn part2(&(lowerbound, upperbound): &(u64, u64)) -> AdventResult<usize> {
+        solve_part2(lowerbound, upperbound)
+    }
+}
+
+pub fn solve_part1(lowerbound: u64, upperbound: u64) -> AdventResult<usize> {
+    let rule = Rule::part1().and(Rule::Range(lowerbound, upperbound));
+    Ok(count_passwords(&rule) as usize)
+}
+
+pub fn solve_part2(lowerbound: u64, upperbound: u64) -> AdventResult<usize> {
+    let rule = Rule::part2().and(Rule::Range(lowerbound, upperbound));
+    Ok(count_passwords(&rule) as usize)
+}
+
+/// Passwords following `rule`, counted digit by digit without going through the candidates.
+/// Rules using `Any` cannot be split by digit, their candidates are checked one by one.
+pub fn count_passwords(rule: &Rule) -> u64 {
+    let plan = Plan::new(rule);
+    if !plan.exact {
+        return Passwords::new(rule).count() as u64;
+    }
+    if plan.lowerbound > plan.upperbound {
+        return 0;
+    }
+    let lengths = match plan.length {
+        Some(length) => length..=length,
+        None => 1..=MAX_LENGTH,
+    };
+    lengths
+        .map(|length| {
+            let below = match plan.lowerbound.checked_sub(1) {
+                Some(n) => count_up_to(&plan, n, length),
+                None => 0,
+            };
+            count_up_to(&plan, plan.upperbound, length) - below
+        })
+        .sum()
+}
+
+// Digits in u64::MAX
+const MAX_LENGTH: usize = 20;
+
+/// Smallest and largest numbers of `length` digits, `None` when there are none in a u64.
+fn length_bounds(length: usize) -> Option<(u64, u64)> {
+    let exponent = u32::try_from(length.checked_sub(1)?).ok()?;
+    let smallest = match exponent {
+        0 => 0,
+        _ => 10u64.checked_pow(exponent)?,
+    };
+    let largest = 10u64.checked_pow(exponent + 1).map_or(u64::MAX, |n| n - 1);
+    Some((smallest, largest))
+}
+
+/// A rule as the digit counter sees it: `All` flattened into its parts. `exact` is false when
+/// some part, an `Any`, could not be taken in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Plan {
+    length: Option<usize>,
+    lowerbound: u64,
+    upperbound: u64,
+    non_decreasing: bool,
+    // Inclusive bounds on the length of a group of matching digits, one group for each
+    runs: Vec<(usize, usize)>,
+    allowed: [bool; 10],
+    exact: bool,
+}
+
+impl Plan {
+    fn new(rule: &Rule) -> Plan {
+        let mut plan = Plan {
+            length: None,
+            lowerbound: 0,
+            upperbound: u64::MAX,
+            non_decreasing: false,
+            runs: Vec::new(),
+            allowed: [true; 10],
+            exact: true,
+        };
+        plan.add(rule);
+        if let Some((smallest, largest)) = plan.length.and_then(length_bounds) {
+            plan.lowerbound = plan.lowerbound.max(smallest);
+            plan.upperbound = plan.upperbound.min(largest);
+        } else if plan.length.is_some() {
+            // No number has that many digits
+            plan.lowerbound = 1;
+            plan.upperbound = 0;
+        }
+        plan
+    }
+
+    fn add(&mut self, rule: &Rule) {
+        match rule {
+            Rule::Length(length) => match self.length {
+                Some(other) if other != *length => {
+                    self.lowerbound = 1;
+                    self.upperbound = 0;
+                }
+                _ => self.length = Some(*length),
+            },
+            Rule::Range(lowerbound, upperbound) => {
+                self.lowerbound = self.lowerbound.max(*lowerbound);
+                self.upperbound = self.upperbound.min(*upperbound);
+            }
+            Rule::NonDecreasing => self.non_decreasing = true,
+            Rule::Run { min, max } => self.runs.push((*min, max.unwrap_or(usize::MAX))),
+            Rule::ForbiddenDigits(forbidden) => {
+                for &digit in forbidden.iter().filter(|&&digit| digit < 10) {
+                    self.allowed[digit as usize] = false;
+                }
+            }
+            Rule::All(rules) => rules.iter().for_each(|rule| self.add(rule)),
+            Rule::Any(_) => self.exact = false,
+        }
+        // One bit per group in the counter's state
+        if self.runs.len() > 64 {
+            self.exact = false;
+        }
+    }
+
+    /// Groups longer than this all behave the same.
+    fn run_cap(&self) -> usize {
+        self.runs
+            .iter()
+            .map(|&(min, max)| if max == usize::MAX { min } else { max + 1 })
+            .max()
+            .unwrap_or(1)
+            .max(1)
+    }
+
+    /// Bit `i` set when a group of `run` matching digits satisfies `runs[i]`.
+    fn accepted(&self, run: usize) -> u64 {
+        self.runs
+            .iter()
+            .enumerate()
+            .filter(|(_, &(min, max))| (min..=max).contains(&run))
+            .fold(0, |mask, (i, _)| mask | 1 << i)
+    }
+}
+
+/// Passwords of `length` digits up to `n` included.
+fn count_up_to(plan: &Plan, n: u64, length: usize) -> u64 {
+    let (smallest, largest) = match length_bounds(length) {
+        Some(bounds) => bounds,
+        None => return 0,
+    };
+    if n < smallest {
+        return 0;
+    }
+    let bound = number_to_vec(n.min(largest));
+    let mut counter = DigitCounter {
+        bound: &bound,
+        plan,
+        run_cap: plan.run_cap(),
+        all_runs: match plan.runs.len() {
+            0 => 0,
+            len => u64::MAX >> (64 - len),
+        },
+        memo: HashMap::new(),
+    };
+    counter.count(0, 0, 0, 0, true)
+}
+
+/// Counts the ways to fill the digits left, knowing the last digit, the length of the group of
+/// matching digits it ends (capped by the plan) and which run rules earlier groups satisfy.
+struct DigitCounter<'a> {
+    bound: &'a [u64],
+    plan: &'a Plan,
+    run_cap: usize,
+    all_runs: u64,
+    memo: HashMap<(usize, u64, usize, u64), u64>,
+}
+
+impl DigitCounter<'_> {
+    fn count(&mut self, position: usize, last: u64, run: usize, done: u64, tight: bool) -> u64 {
+        if position == self.bound.len() {
+            return (done | self.plan.accepted(run) == self.all_runs) as u64;
+        }
+        let key = (position, last, run, done);
+        if !tight {
+            if let Some(&count) = self.memo.get(&key) {
+                return count;
+            }
+        }
+        // No leading zero, unless the password is 0 itself
+        let first = match position {
+            0 if self.bound.len() > 1 => 1,
+            0 => 0,
+            _ if self.plan.non_decreasing => last,
+            _ => 0,
+        };
+        let limit = if tight { self.bound[position] } else { 9 };
+        let mut count = 0;
+        for digit in first..=limit {
+            if !self.plan.allowed[digit as usize] {
+                continue;
+            }
+            let (run, done) = if position > 0 && digit == last {
+                ((run + 1).min(self.run_cap), done)
+            } else {
+                (1, done | self.plan.accepted(run))
+            };
+            let tight = tight && digit == limit;
+            count += self.count(position + 1, digit, run, done, tight);
+        }
+        if !tight {
+            self.memo.insert(key, count);
+        }
+        count
+    }
+}
+
+/// Passwords following a rule in increasing order. When the rule wants digits that never
+/// decrease, the numbers whose digits do are jumped over.
+pub struct Passwords<'a> {
+    rule: &'a Rule,
+    next: Option<u64>,
+    upperbound: u64,
+    non_decreasing: bool,
+}
+
+impl<'a> Passwords<'a> {
+    pub fn new(rule: &'a Rule) -> Self {
+        let plan = Plan::new(rule);
+        Self {
+            rule,
+            next: Some(plan.lowerbound),
+            upperbound: plan.upperbound,
+            non_decreasing: plan.non_decreasing,
+        }
+    }
+}
+
+impl Iterator for Passwords<'_> {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        loop {
+            let mut digits = number_to_vec(self.next?);
+            // Smallest number from here on whose digits never decrease
+            if self.non_decreasing {
+                if let Some(i) = (1..digits.len()).find(|&i| digits[i] < digits[i - 1]) {
+                    let fill = digits[i - 1];
+                    digits[i..].iter_mut().for_each(|digit| *digit = fill);
+                }
+            }
+            let n = digits.iter().fold(0, |n, digit| n * 10 + digit);
+            if n > self.upperbound {
+                self.next = None;
+                return None;
+            }
+            self.next = n.checked_add(1);
+            if self.rule.holds(n, &digits) {
+                return Some(n);
+            }
+        }
+    }
+}
+
+pub fn number_to_vec(n: u64) -> Vec<u64> {
+    let mut digits = Vec::with_capacity(10);
+    let mut n = n;
+
+    while n > 9 {
+        digits.push(n % 10);
+        n /= 10;
+    }
+    digits.push(n);
+    digits.reverse();
+    digits
+}
+
+/// Lengths of the groups of matching adjacent digits, e.g. `[2, 1, 3]` for 112333.
+fn runs(digits: &[u64]) -> Vec<usize> {
+    let mut runs: Vec<usize> = Vec::new();
+    for (i, digit) in digits.iter().enumerate() {
+        match runs.last_mut() {
+            Some(run) if digits[i - 1] == *digit => *run += 1,
+            _ => runs.push(1),
+        }
+    }
+    runs
+}
+
+/// A condition on passwords. Rules combine with `and`, or with `All` and `Any` directly.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Rule {
+    /// Number of digits
+    Length(usize),
+    /// Inclusive bounds
+    Range(u64, u64),
+    /// Going from left to right, the digits never decrease
+    NonDecreasing,
+    /// At least one group of matching adjacent digits with a length within `min..=max`
+    Run {
+        min: usize,
+        max: Option<usize>,
+    },
+    ForbiddenDigits(Vec<u64>),
+    All(Vec<Rule>),
+    Any(Vec<Rule>),
+}
+
+impl Rule {
+    /// Part 1: six digits that never decrease, two adjacent ones being the same.
+    pub fn part1() -> Rule {
+        Rule::Length(6)
+            .and(Rule::NonDecreasing)
+            .and(Rule::Run { min: 2, max: None })
+    }
+
+    /// Part 2: part 1, with a pair of matching digits that is not part of a larger group.
+    pub fn part2() -> Rule {
+        Rule::part1().and(Rule::Run {
+            min: 2,
+            max: Some(2),
+        })
+    }
+
+    pub fn and(self, other: Rule) -> Rule {
+        match self {
+            Rule::All(mut rules) => {
+                rules.push(other);
+                Rule::All(rules)
+            }
+            rule => Rule::All(vec![rule, other]),
+        }
+    }
+
+    pub fn is_valid(&self, password: u64) -> bool {
+        self.holds(password, &number_to_vec(password))
+    }
+
+    fn holds(&self, password: u64, digits: &[u64]) -> bool {
+        match self {
+            Rule::Length(length) => digits.len() == *length,
+            Rule::Range(lowerbound, upperbound) => (*lowerbound..=*upperbound).contains(&password),
+            Rule::NonDecreasing => digits.windows(2).all(|pair| pair[0] <= pair[1]),
+            Rule::Run { min, max } => {
+                let max = max.unwrap_or(usize::MAX);
+                runs(digits).iter().any(|run| (*min..=max).contains(run))
+            }
+            Rule::ForbiddenDigits(forbidden) => !digits.iter().any(|d| forbidden.contains(d)),
+            Rule::All(rules) => rules.iter().all(|rule| rule.holds(password, digits)),
+            Rule::Any(rules) => rules.iter().any(|rule| rule.holds(password, digits)),
+        }
+    }
+
+    /// Explains every way in which `password` breaks the rule.
+    pub fn check(&self, password: u64) -> Result<(), Violation> {
+        let reasons = self.reasons(password);
+        if reasons.is_empty() {
+            Ok(())
+        } else {
+            Err(Violation { password, reasons })
+        }
+    }
+
+    fn reasons(&self, password: u64) -> Vec<String> {
+        let digits = number_to_vec(password);
+        if self.holds(password, &digits) {
+            return Vec::new();
+        }
+        let reason = match self {
+            Rule::Length(length) => format!("has {} digits instead of {}", digits.len(), length),
+            Rule::Range(lowerbound, upperbound) => {
+                format!("is not within {}-{}", lowerbound, upperbound)
+            }
+            Rule::NonDecreasing => {
+                let pair = digits.windows(2).find(|pair| pair[1] < pair[0]);
+                let pair = pair.expect("a decreasing pair");
+                format!("decreases from {} to {}", pair[0], pair[1])
+            }
+            Rule::Run { min, max } => {
+                let size = match max {
+                    None => format!("at least {}", min),
+                    Some(max) if max == min => format!("exactly {}", min),
+                    Some(max) => format!("{} to {}", min, max),
+                };
+                format!("has no group of {} matching digits", size)
+            }
+            Rule::ForbiddenDigits(forbidden) => {
+                let digit = digits.iter().find(|digit| forbidden.contains(digit));
+                format!("contains the forbidden digit {}", digit.expect("a digit"))
+            }
+            Rule::All(rules) => return rules.iter().flat_map(|r| r.reasons(password)).collect(),
+            Rule::Any(rules) => rules
+                .iter()
+                .map(|rule| rule.reasons(password).join(" and "))
+                .collect::<Vec<_>>()
+                .join(", or "),
+        };
+        vec![reason]
+    }
+}
+
+/// Why a password was rejected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    pub password: u64,
+    pub reasons: Vec<String>,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.password, self.reasons.join("; "))
+    }
+}
+
+impl error::Error for Violation {}
+
+#[test]
+fn test_cases_part1() {
+    assert!(Rule::part1().is_valid(111_111));
+    assert!(!Rule::part1().is_valid(223_450));
+    assert!(!Rule::part1().is_valid(123_789));
+}
+
+#[test]
+fn test_cases_part2() {
+    assert!(Rule::part2().is_valid(112_233));
+    assert!(!Rule::part2().is_valid(123_444));
+    assert!(Rule::part2().is_valid(111_122));
+}
+
+#[test]
+fn test_day04_rule_explanations() {
+    let violation = Rule::part1().check(223_450).unwrap_err();
+    assert_eq!(violation.to_string(), "223450 decreases from 5 to 0");
+    let violation = Rule::part2().check(123_444).unwrap_err();
+    assert_eq!(
+        violation.reasons,
+        vec!["has no group of exactly 2 matching digits"]
+    );
+    let violation = Rule::part1().check(12_345).unwrap_err();
+    assert_eq!(
+        violation.reasons,
+        vec![
+            "has 5 digits instead of 6",
+            "has no group of at least 2 matching digits"
+        ]
+    );
+
+    let rule = Rule::Range(100_000, 200_000)
+        .and(Rule::ForbiddenDigits(vec![7]))
+        .and(Rule::Any(vec![
+            Rule::Run {
+                min: 3,
+                max: Some(4),
+            },
+            Rule::Length(7),
+        ]));
+    assert!(rule.is_valid(111_234));
+    assert_eq!(
+        rule.check(123_477).unwrap_err().reasons,
+        vec![
+            "contains the forbidden digit 7",
+            "has no group of 3 to 4 matching digits, or has 6 digits instead of 7"
+        ]
+    );
+    assert!(!rule.is_valid(311_123));
+}
+
+#[cfg(test)]
+fn brute_force(rule: &Rule, lowerbound: u64, upperbound: u64) -> Vec<u64> {
+    (lowerbound..=upperbound)
+        .filter(|&n| rule.is_valid(n))
+        .collect()
+}
+
+#[test]
+fn test_day04_count_matches_brute_force() {
+    for &(lowerbound, upperbound) in &[(0, 999_999), (353_096, 843_212), (111_111, 111_122)] {
+        for rule in &[Rule::part1(), Rule::part2()] {
+            let expected = brute_force(rule, lowerbound, upperbound);
+            let rule = rule.clone().and(Rule::Range(lowerbound, upperbound));
+            assert_eq!(count_passwords(&rule), expected.len() as u64);
+            let generated: Vec<u64> = Passwords::new(&rule).collect();
+            assert_eq!(generated, expected);
+        }
+    }
+}
+
+#[test]
+fn test_day04_other_rules() {
+    let rules = [
+        // Digits may decrease
+        Rule::Run {
+            min: 3,
+            max: Some(3),
+        },
+        Rule::Length(4).and(Rule::ForbiddenDigits(vec![0, 5])),
+        // Two groups, one of two or three digits and one of at least four
+        Rule::NonDecreasing
+            .and(Rule::Run {
+                min: 2,
+                max: Some(3),
+            })
+            .and(Rule::Run { min: 4, max: None }),
+        Rule::Length(3).and(Rule::Length(4)),
+        Rule::Any(vec![Rule::Length(2), Rule::Run { min: 4, max: None }]),
+    ];
+    for rule in rules.iter() {
+        let expected = brute_force(rule, 0, 99_999);
+        let rule = rule.clone().and(Rule::Range(0, 99_999));
+        assert_eq!(count_passwords(&rule), expected.len() as u64);
+        assert_eq!(Passwords::new(&rule).collect::<Vec<_>>(), expected);
+    }
+}
+
+#[test]
+fn test_day04_other_lengths() {
+    let pairs = |length| {
+        Rule::Length(length)
+            .and(Rule::NonDecreasing)
+            .and(Rule::Run { min: 2, max: None })
+    };
+    // 11, 22, ..., 99
+    assert_eq!(count_passwords(&pairs(2)), 9);
+    for length in 0..=8 {
+        let rule = pairs(length);
+        assert_eq!(Passwords::new(&rule).count() as u64, count_passwords(&rule));
+    }
+    assert_eq!(count_passwords(&pairs(21)), 0);
+    assert_eq!(count_passwords(&Rule::part1().and(Rule::Range(5, 4))), 0);
+    assert_eq!(count_passwords(&Rule::Length(1)), 10);
+    assert_eq!(
+        count_passwords(&Rule::Length(20)),
+        u64::MAX - 10u64.pow(19) + 1
+    );
+}
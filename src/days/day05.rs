@@ -1,22 +1,28 @@
-use advent2019::intcode::get_program_last_output;
-use advent2019::{get_input, AdventResult};
+use crate::intcode::get_program_last_output;
+use crate::solution::Solution;
+use crate::{parse_input, AdventResult};
 
-fn main() -> AdventResult<()> {
-    println!("Intcode diagnostic code: {}", solve_part1()?);
-    println!("Intcode diagnostic code: {}", solve_part2()?);
-    Ok(())
-}
+pub struct Day05;
 
-pub fn solve_part1() -> AdventResult<isize> {
-    let code = &get_input::<isize>(5)?.first_row();
-    let input = &[1];
-    Ok(get_program_last_output(code, input))
-}
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    type Input = Vec<isize>;
+    type Part1 = isize;
+    type Part2 = isize;
+
+    fn parse(input: &str) -> AdventResult<Vec<isize>> {
+        parse_input::<isize>(input)?.first_row()
+    }
+
+    fn part1(code: &Vec<isize>) -> AdventResult<isize> {
+        let input = &[1];
+        Ok(get_program_last_output(code, input))
+    }
 
-pub fn solve_part2() -> AdventResult<isize> {
-    let code = &get_input::<isize>(5)?.first_row();
-    let input = &[5];
-    Ok(get_program_last_output(code, input))
+    fn part2(code: &Vec<isize>) -> AdventResult<isize> {
+        let input = &[5];
+        Ok(get_program_last_output(code, input))
+    }
 }
 
 #[test]
@@ -0,0 +1,397 @@
+use crate::solution::Solution;
+use crate::{parse_input_with_params, AdventError, AdventResult};
+use std::collections::{HashMap, HashSet};
+use std::{error, fmt};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    type Input = OrbitTree;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> AdventResult<OrbitTree> {
+        let orbit_map = parse_input_with_params::<String>(input, false, ')')?.get_data();
+        let orbits = orbit_map
+            .iter()
+            .map(|orbit| match &orbit[..] {
+                [center, object] => Ok((center, object)),
+                _ => Err(AdventError::InvalidValue),
+            })
+            .collect::<AdventResult<Vec<_>>>()?;
+        OrbitTree::new(&orbits)
+    }
+
+    fn part1(tree: &OrbitTree) -> AdventResult<u64> {
+        Ok(tree.total_orbits())
+    }
+
+    fn part2(tree: &OrbitTree) -> AdventResult<u64> {
+        tree.transfers("YOU", "SAN")
+            .ok_or(AdventError::InvalidValue)
+    }
+}
+
+/// Why an orbit map is not a single tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum OrbitError {
+    /// An object orbiting two different objects
+    DuplicateParent {
+        object: String,
+        parents: (String, String),
+    },
+    /// Objects orbiting nothing, when there should be exactly one
+    MultipleRoots(Vec<String>),
+    /// Objects orbiting each other in turn
+    Cycle(Vec<String>),
+}
+
+impl fmt::Display for OrbitError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            OrbitError::DuplicateParent { object, parents } => {
+                write!(f, "{} orbits both {} and {}", object, parents.0, parents.1)
+            }
+            OrbitError::MultipleRoots(roots) => {
+                write!(f, "Several objects orbit nothing: {}", roots.join(", "))
+            }
+            OrbitError::Cycle(cycle) => write!(f, "Orbits go round: {}", cycle.join(")")),
+        }
+    }
+}
+
+impl error::Error for OrbitError {}
+
+/// The orbit map as a rooted tree, every object orbiting its parent.
+///
+/// Ancestors are found by binary lifting: `ancestors[k][i]` is the object `2^k` orbits up from
+/// object `i`, so any ancestor and the lowest common ancestor of any pair take `O(log n)` steps.
+pub struct OrbitTree {
+    names: Vec<String>,
+    index: HashMap<String, usize>,
+    children: Vec<Vec<usize>>,
+    depth: Vec<u64>,
+    ancestors: Vec<Vec<Option<usize>>>,
+    subtree_size: Vec<usize>,
+}
+
+impl OrbitTree {
+    /// Builds the tree from `(center, object)` pairs, `object` orbiting `center`. Fails with
+    /// `InvalidInput` explaining why when the orbits do not form a single tree.
+    pub fn new<T: AsRef<str>>(orbits: &[(T, T)]) -> AdventResult<Self> {
+        let mut names: Vec<String> = Vec::new();
+        let mut index: HashMap<String, usize> = HashMap::new();
+        let mut id = |name: &str| -> usize {
+            *index.entry(name.to_string()).or_insert_with(|| {
+                names.push(name.to_string());
+                names.len() - 1
+            })
+        };
+        let orbits: Vec<(usize, usize)> = orbits
+            .iter()
+            .map(|(center, object)| (id(center.as_ref()), id(object.as_ref())))
+            .collect();
+
+        let mut parent: Vec<Option<usize>> = vec![None; names.len()];
+        let mut children = vec![Vec::new(); names.len()];
+        for &(center, object) in orbits.iter() {
+            match parent[object] {
+                Some(other) if other == center => continue,
+                Some(other) => {
+                    return Err(OrbitError::DuplicateParent {
+                        object: names[object].clone(),
+                        parents: (names[other].clone(), names[center].clone()),
+                    }
+                    .into())
+                }
+                None => parent[object] = Some(center),
+            }
+            children[center].push(object);
+        }
+
+        let roots: Vec<usize> = (0..names.len()).filter(|&i| parent[i].is_none()).collect();
+        if roots.len() > 1 {
+            let mut roots: Vec<String> = roots.iter().map(|&i| names[i].clone()).collect();
+            roots.sort();
+            return Err(OrbitError::MultipleRoots(roots).into());
+        }
+
+        // Parents come before their children
+        let mut order: Vec<usize> = roots.clone();
+        let mut depth = vec![0; names.len()];
+        let mut i = 0;
+        while i < order.len() {
+            let center = order[i];
+            for &object in children[center].iter() {
+                depth[object] = depth[center] + 1;
+                order.push(object);
+            }
+            i += 1;
+        }
+        if order.len() < names.len() {
+            // Whatever was not reached hangs from a cycle
+            let reached: HashSet<usize> = order.iter().copied().collect();
+            let start = (0..names.len()).find(|i| !reached.contains(i));
+            let cycle = Self::cycle(&parent, start.expect("an unreached object"));
+            let cycle = cycle.iter().map(|&i| names[i].clone()).collect();
+            return Err(OrbitError::Cycle(cycle).into());
+        }
+
+        let mut subtree_size = vec![1; names.len()];
+        for &object in order.iter().rev() {
+            if let Some(center) = parent[object] {
+                subtree_size[center] += subtree_size[object];
+            }
+        }
+
+        let mut ancestors = vec![parent];
+        let max_depth = depth.iter().copied().max().unwrap_or(0);
+        while 1u64 << (ancestors.len() - 1) < max_depth {
+            let previous = ancestors.last().expect("parents");
+            let next = previous
+                .iter()
+                .map(|a| a.and_then(|a| previous[a]))
+                .collect();
+            ancestors.push(next);
+        }
+
+        Ok(Self {
+            names,
+            index,
+            children,
+            depth,
+            ancestors,
+            subtree_size,
+        })
+    }
+
+    /// Follows the parents from `start` until coming back to an object already seen.
+    fn cycle(parent: &[Option<usize>], start: usize) -> Vec<usize> {
+        let mut path = vec![start];
+        let mut seen: HashMap<usize, usize> = HashMap::new();
+        seen.insert(start, 0);
+        let mut current = start;
+        // Unreached objects always have a parent, or they would be roots
+        while let Some(next) = parent[current] {
+            if let Some(&position) = seen.get(&next) {
+                let mut cycle = path.split_off(position);
+                cycle.reverse();
+                return cycle;
+            }
+            seen.insert(next, path.len());
+            path.push(next);
+            current = next;
+        }
+        unreachable!("objects outside the tree orbit in a cycle")
+    }
+
+    fn id(&self, object: &str) -> Option<usize> {
+        self.index.get(object).copied()
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn root(&self) -> Option<&str> {
+        (0..self.len())
+            .find(|&i| self.depth[i] == 0)
+            .map(|i| self.names[i].as_str())
+    }
+
+    /// Number of direct and indirect orbits of `object`.
+    pub fn depth(&self, object: &str) -> Option<u64> {
+        self.id(object).map(|i| self.depth[i])
+    }
+
+    /// Objects directly orbiting `object`.
+    pub fn satellites(&self, object: &str) -> Option<Vec<&str>> {
+        let i = self.id(object)?;
+        Some(
+            self.children[i]
+                .iter()
+                .map(|&child| self.names[child].as_str())
+                .collect(),
+        )
+    }
+
+    /// `object` and everything orbiting it, directly or not.
+    pub fn subtree_size(&self, object: &str) -> Option<usize> {
+        self.id(object).map(|i| self.subtree_size[i])
+    }
+
+    /// Sum of the direct and indirect orbits of every object (part 1).
+    pub fn total_orbits(&self) -> u64 {
+        self.depth.iter().sum()
+    }
+
+    fn ancestor_id(&self, mut i: usize, generations: u64) -> Option<usize> {
+        if generations > self.depth[i] {
+            return None;
+        }
+        for (k, level) in self.ancestors.iter().enumerate() {
+            if generations & (1 << k) != 0 {
+                i = level[i]?;
+            }
+        }
+        Some(i)
+    }
+
+    /// The object `generations` orbits up from `object`, `object` itself for 0.
+    pub fn ancestor(&self, object: &str, generations: u64) -> Option<&str> {
+        let i = self.ancestor_id(self.id(object)?, generations)?;
+        Some(&self.names[i])
+    }
+
+    fn common_ancestor_id(&self, a: usize, b: usize) -> usize {
+        let (a, b) = if self.depth[a] >= self.depth[b] {
+            (a, b)
+        } else {
+            (b, a)
+        };
+        let mut a = self
+            .ancestor_id(a, self.depth[a] - self.depth[b])
+            .expect("an ancestor at the same depth");
+        let mut b = b;
+        if a == b {
+            return a;
+        }
+        for level in self.ancestors.iter().rev() {
+            if let (Some(parent_a), Some(parent_b)) = (level[a], level[b]) {
+                if parent_a != parent_b {
+                    a = parent_a;
+                    b = parent_b;
+                }
+            }
+        }
+        self.ancestors[0][a].expect("a common parent")
+    }
+
+    /// The deepest object both `a` and `b` orbit, directly or not, or are.
+    pub fn common_ancestor(&self, a: &str, b: &str) -> Option<&str> {
+        let i = self.common_ancestor_id(self.id(a)?, self.id(b)?);
+        Some(&self.names[i])
+    }
+
+    /// Number of orbits between `a` and `b`, through their common ancestor.
+    pub fn distance(&self, a: &str, b: &str) -> Option<u64> {
+        let (a, b) = (self.id(a)?, self.id(b)?);
+        let common = self.common_ancestor_id(a, b);
+        Some(self.depth[a] + self.depth[b] - 2 * self.depth[common])
+    }
+
+    /// Orbital transfers to move from the object `a` orbits to the one `b` orbits (part 2).
+    pub fn transfers(&self, a: &str, b: &str) -> Option<u64> {
+        let (parent_a, parent_b) = (self.ancestor(a, 1)?, self.ancestor(b, 1)?);
+        self.distance(parent_a, parent_b)
+    }
+}
+
+impl From<OrbitError> for AdventError {
+    fn from(err: OrbitError) -> AdventError {
+        AdventError::InvalidInput(Box::new(err))
+    }
+}
+
+#[cfg(test)]
+fn example_tree() -> OrbitTree {
+    let orbits = [
+        ("COM", "B"),
+        ("B", "C"),
+        ("C", "D"),
+        ("D", "E"),
+        ("E", "F"),
+        ("B", "G"),
+        ("G", "H"),
+        ("D", "I"),
+        ("E", "J"),
+        ("J", "K"),
+        ("K", "L"),
+        ("K", "YOU"),
+        ("I", "SAN"),
+    ];
+    OrbitTree::new(&orbits).unwrap()
+}
+
+#[test]
+fn test_day6_case_part1() {
+    let orbits = [
+        ("COM", "B"),
+        ("B", "C"),
+        ("C", "D"),
+        ("D", "E"),
+        ("E", "F"),
+        ("B", "G"),
+        ("G", "H"),
+        ("D", "I"),
+        ("E", "J"),
+        ("J", "K"),
+        ("K", "L"),
+    ];
+    assert_eq!(OrbitTree::new(&orbits).unwrap().total_orbits(), 42);
+}
+
+#[test]
+fn test_day6_case_part2() {
+    assert_eq!(example_tree().transfers("YOU", "SAN").unwrap(), 4);
+}
+
+#[test]
+fn test_day6_tree_queries() {
+    let tree = example_tree();
+    assert_eq!(tree.root(), Some("COM"));
+    assert_eq!(tree.depth("L"), Some(7));
+    assert_eq!(tree.depth("COM"), Some(0));
+    assert_eq!(tree.ancestor("L", 3), Some("E"));
+    assert_eq!(tree.ancestor("L", 8), None);
+    assert_eq!(tree.common_ancestor("YOU", "SAN"), Some("D"));
+    assert_eq!(tree.common_ancestor("H", "F"), Some("B"));
+    assert_eq!(tree.common_ancestor("E", "L"), Some("E"));
+    assert_eq!(tree.distance("H", "L"), Some(8));
+    assert_eq!(tree.subtree_size("E"), Some(6));
+    assert_eq!(tree.subtree_size("COM"), Some(tree.len()));
+    assert_eq!(tree.satellites("K"), Some(vec!["L", "YOU"]));
+    assert_eq!(tree.depth("X"), None);
+}
+
+#[cfg(test)]
+fn orbit_error(tree: AdventResult<OrbitTree>) -> Option<OrbitError> {
+    match tree {
+        Err(AdventError::InvalidInput(err)) => err.downcast_ref::<OrbitError>().cloned(),
+        _ => None,
+    }
+}
+
+#[test]
+fn test_day6_invalid_maps() {
+    let duplicate = OrbitTree::new(&[("COM", "A"), ("COM", "B"), ("B", "A")]);
+    assert_eq!(
+        orbit_error(duplicate),
+        Some(OrbitError::DuplicateParent {
+            object: "A".to_string(),
+            parents: ("COM".to_string(), "B".to_string())
+        })
+    );
+
+    let roots = OrbitTree::new(&[("COM", "A"), ("X", "B")]);
+    assert_eq!(
+        orbit_error(roots),
+        Some(OrbitError::MultipleRoots(vec![
+            "COM".to_string(),
+            "X".to_string()
+        ]))
+    );
+
+    let cycle = OrbitTree::new(&[("COM", "A"), ("B", "C"), ("C", "D"), ("D", "B"), ("D", "E")]);
+    let err = cycle.err().unwrap();
+    assert!(err
+        .to_string()
+        .starts_with("Invalid input: Orbits go round"));
+    let cycle = orbit_error(Err(err)).unwrap();
+    assert!(matches!(&cycle, OrbitError::Cycle(objects) if objects.len() == 3));
+}
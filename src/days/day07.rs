@@ -0,0 +1,319 @@
+use crate::intcode::IntCode;
+use crate::parallel::Pool;
+use crate::solution::{Auxiliary, Solution};
+use crate::{parse_input, AdventError, AdventResult};
+use itertools::Itertools;
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    type Input = Vec<isize>;
+    type Part1 = isize;
+    type Part2 = isize;
+
+    fn parse(input: &str) -> AdventResult<Vec<isize>> {
+        parse_input::<isize>(input)?.first_row()
+    }
+
+    fn part1(code: &Vec<isize>) -> AdventResult<isize> {
+        Ok(best_chain(code)?.signal)
+    }
+
+    fn part2(code: &Vec<isize>) -> AdventResult<isize> {
+        Ok(best_feedback_loop(code)?.signal)
+    }
+
+    fn auxiliary(code: &Vec<isize>) -> AdventResult<Auxiliary> {
+        let mut auxiliary = Auxiliary::new();
+        auxiliary.insert("phases".to_string(), best_chain(code)?.phases.into());
+        auxiliary.insert(
+            "feedback_phases".to_string(),
+            best_feedback_loop(code)?.phases.into(),
+        );
+        Ok(auxiliary)
+    }
+}
+
+fn best_chain(code: &[isize]) -> AdventResult<Best> {
+    PhaseSearch::new(code, &[0, 1, 2, 3, 4])
+        .best()
+        .ok_or(AdventError::InvalidValue)
+}
+
+fn best_feedback_loop(code: &[isize]) -> AdventResult<Best> {
+    PhaseSearch::new(code, &[5, 6, 7, 8, 9])
+        .feedback(true)
+        .best()
+        .ok_or(AdventError::InvalidValue)
+}
+
+/// Amplifier VM that has read its phase setting and waits for its input signal.
+fn primed(program: &[isize], phase: isize) -> IntCode {
+    let mut amplifier = IntCode::new(program);
+    amplifier.run_till_blocked(&[phase]);
+    amplifier
+}
+
+/// Signal out of amplifiers chained one after the other, set to `phases`.
+pub fn run_amplifiers(program: &[isize], phases: &[isize]) -> Option<isize> {
+    phases.iter().try_fold(0, |signal, &phase| {
+        primed(program, phase)
+            .run_till_blocked(&[signal])
+            .last()
+            .copied()
+    })
+}
+
+/// Signal out of the last amplifier when it halts, its output going back to the first one.
+pub fn run_amplifiers_feedback_loop(program: &[isize], phases: &[isize]) -> Option<isize> {
+    let amplifiers: Vec<IntCode> = phases.iter().map(|&phase| primed(program, phase)).collect();
+    feedback_loop(amplifiers)
+}
+
+fn feedback_loop(mut amplifiers: Vec<IntCode>) -> Option<isize> {
+    let mut signal = 0;
+    while !amplifiers.last()?.has_halted() {
+        for amplifier in amplifiers.iter_mut() {
+            signal = *amplifier.run_till_blocked(&[signal]).last()?;
+        }
+    }
+    Some(signal)
+}
+
+/// Best phase settings found by a `PhaseSearch` and the signal they give.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Best {
+    pub phases: Vec<isize>,
+    pub signal: isize,
+}
+
+/// Replaces `best` only with a strictly higher signal, so the first ordering wins ties.
+fn keep_best(best: &mut Option<Best>, candidate: Best) {
+    if best
+        .as_ref()
+        .is_none_or(|best| candidate.signal > best.signal)
+    {
+        *best = Some(candidate);
+    }
+}
+
+/// Tries every ordering of distinct phase settings over the amplifiers, keeping the one sending
+/// the highest signal to the thrusters. Ties go to the first ordering, following `phases`.
+pub struct PhaseSearch<'a> {
+    program: &'a [isize],
+    phases: Vec<isize>,
+    amplifiers: usize,
+    feedback: bool,
+}
+
+impl<'a> PhaseSearch<'a> {
+    /// One amplifier per phase setting, chained one after the other.
+    pub fn new(program: &'a [isize], phases: &[isize]) -> Self {
+        Self {
+            program,
+            phases: phases.to_vec(),
+            amplifiers: phases.len(),
+            feedback: false,
+        }
+    }
+
+    pub fn amplifiers(mut self, amplifiers: usize) -> Self {
+        self.amplifiers = amplifiers;
+        self
+    }
+
+    /// Wires the last amplifier's output back into the first one.
+    pub fn feedback(mut self, feedback: bool) -> Self {
+        self.feedback = feedback;
+        self
+    }
+
+    pub fn best(&self) -> Option<Best> {
+        if self.amplifiers == 0 || self.amplifiers > self.phases.len() {
+            return None;
+        }
+        // Amplifiers only differ by their phase until they get a signal
+        let primed: Vec<IntCode> = self
+            .phases
+            .iter()
+            .map(|&phase| primed(self.program, phase))
+            .collect();
+        if self.feedback {
+            self.best_feedback_loop(&primed)
+        } else {
+            self.best_chain(&primed)
+        }
+    }
+
+    /// One branch of the orderings per first amplifier, each on its own thread.
+    fn best_chain(&self, primed: &[IntCode]) -> Option<Best> {
+        let firsts: Vec<usize> = (0..primed.len()).collect();
+        let branches = Pool::default().map(&firsts, |&first| {
+            let mut best = None;
+            let mut amplifier = primed[first].clone();
+            if let Some(&output) = amplifier.run_till_blocked(&[0]).last() {
+                self.chain(primed, &mut vec![first], output, &mut best);
+            }
+            best
+        });
+        let mut best = None;
+        for branch in branches.into_iter().flatten() {
+            keep_best(&mut best, branch);
+        }
+        best
+    }
+
+    /// Depth first over the orderings, so the signal out of a prefix is computed once for all
+    /// the orderings sharing it.
+    fn chain(
+        &self,
+        primed: &[IntCode],
+        used: &mut Vec<usize>,
+        signal: isize,
+        best: &mut Option<Best>,
+    ) {
+        if used.len() == self.amplifiers {
+            let phases = used.iter().map(|&i| self.phases[i]).collect();
+            keep_best(best, Best { phases, signal });
+            return;
+        }
+        for i in 0..primed.len() {
+            if used.contains(&i) {
+                continue;
+            }
+            let mut amplifier = primed[i].clone();
+            if let Some(&output) = amplifier.run_till_blocked(&[signal]).last() {
+                used.push(i);
+                self.chain(primed, used, output, best);
+                used.pop();
+            }
+        }
+    }
+
+    /// The loop needs every phase before any signal is known, each ordering runs on its own.
+    fn best_feedback_loop(&self, primed: &[IntCode]) -> Option<Best> {
+        let orderings: Vec<Vec<usize>> = (0..primed.len()).permutations(self.amplifiers).collect();
+        let signals = Pool::default().map(&orderings, |ordering| {
+            feedback_loop(ordering.iter().map(|&i| primed[i].clone()).collect())
+        });
+        let mut best: Option<Best> = None;
+        for (ordering, signal) in orderings.iter().zip(signals) {
+            let signal = match signal {
+                Some(signal) => signal,
+                None => continue,
+            };
+            let phases = ordering.iter().map(|&i| self.phases[i]).collect();
+            keep_best(&mut best, Best { phases, signal });
+        }
+        best
+    }
+}
+
+// TESTS
+#[test]
+fn test_day7_case1_amplifiers() {
+    let prog = &[
+        3, 15, 3, 16, 1002, 16, 10, 16, 1, 16, 15, 15, 4, 15, 99, 0, 0,
+    ];
+    assert_eq!(run_amplifiers(prog, &[4, 3, 2, 1, 0]), Some(43210));
+    let search = PhaseSearch::new(prog, &[0, 1, 2, 3, 4]);
+    assert_eq!(
+        search.best(),
+        Some(Best {
+            phases: vec![4, 3, 2, 1, 0],
+            signal: 43210
+        })
+    );
+}
+
+#[test]
+fn test_day7_case2_amplifiers() {
+    let prog = &[
+        3, 23, 3, 24, 1002, 24, 10, 24, 1002, 23, -1, 23, 101, 5, 23, 23, 1, 24, 23, 23, 4, 23, 99,
+        0, 0,
+    ];
+    assert_eq!(run_amplifiers(prog, &[0, 1, 2, 3, 4]), Some(54321));
+    let search = PhaseSearch::new(prog, &[0, 1, 2, 3, 4]);
+    assert_eq!(
+        search.best(),
+        Some(Best {
+            phases: vec![0, 1, 2, 3, 4],
+            signal: 54321
+        })
+    );
+}
+
+#[test]
+fn test_day7_case3_amplifiers() {
+    let prog = &[
+        3, 31, 3, 32, 1002, 32, 10, 32, 1001, 31, -2, 31, 1007, 31, 0, 33, 1002, 33, 7, 33, 1, 33,
+        31, 31, 1, 32, 31, 31, 4, 31, 99, 0, 0, 0,
+    ];
+    assert_eq!(run_amplifiers(prog, &[1, 0, 4, 3, 2]), Some(65210));
+    let search = PhaseSearch::new(prog, &[0, 1, 2, 3, 4]);
+    assert_eq!(
+        search.best(),
+        Some(Best {
+            phases: vec![1, 0, 4, 3, 2],
+            signal: 65210
+        })
+    );
+}
+
+#[test]
+fn test_day7_case1_part2() {
+    let prog = &[
+        3, 26, 1001, 26, -4, 26, 3, 27, 1002, 27, 2, 27, 1, 27, 26, 27, 4, 27, 1001, 28, -1, 28,
+        1005, 28, 6, 99, 0, 0, 5,
+    ];
+    assert_eq!(
+        run_amplifiers_feedback_loop(prog, &[9, 8, 7, 6, 5]),
+        Some(139_629_729)
+    );
+    let search = PhaseSearch::new(prog, &[5, 6, 7, 8, 9]).feedback(true);
+    assert_eq!(
+        search.best(),
+        Some(Best {
+            phases: vec![9, 8, 7, 6, 5],
+            signal: 139_629_729
+        })
+    );
+}
+
+#[test]
+fn test_day7_case2_part2() {
+    let prog = &[
+        3, 52, 1001, 52, -5, 52, 3, 53, 1, 52, 56, 54, 1007, 54, 5, 55, 1005, 55, 26, 1001, 54, -5,
+        54, 1105, 1, 12, 1, 53, 54, 53, 1008, 54, 0, 55, 1001, 55, 1, 55, 2, 53, 55, 53, 4, 53,
+        1001, 56, -1, 56, 1005, 56, 6, 99, 0, 0, 0, 0, 10,
+    ];
+    assert_eq!(
+        run_amplifiers_feedback_loop(prog, &[9, 7, 8, 5, 6]),
+        Some(18216)
+    );
+    let search = PhaseSearch::new(prog, &[5, 6, 7, 8, 9]).feedback(true);
+    assert_eq!(
+        search.best(),
+        Some(Best {
+            phases: vec![9, 7, 8, 5, 6],
+            signal: 18216
+        })
+    );
+}
+
+#[test]
+fn test_day7_configurable_search() {
+    let prog = &[
+        3, 15, 3, 16, 1002, 16, 10, 16, 1, 16, 15, 15, 4, 15, 99, 0, 0,
+    ];
+    // Each amplifier appends its phase as a digit: three out of five phases
+    let best = PhaseSearch::new(prog, &[0, 1, 2, 3, 4])
+        .amplifiers(3)
+        .best()
+        .unwrap();
+    assert_eq!(best.phases, vec![4, 3, 2]);
+    assert_eq!(best.signal, 432);
+    assert_eq!(run_amplifiers(prog, &best.phases), Some(best.signal));
+    assert_eq!(PhaseSearch::new(prog, &[0, 1]).amplifiers(3).best(), None);
+}
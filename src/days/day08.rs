@@ -0,0 +1,369 @@
+use crate::grid::Grid;
+use crate::render::{self, Rgb};
+use crate::solution::{Auxiliary, Params, Solution};
+use crate::{ocr, parse_input, AdventError, AdventResult};
+use std::fmt;
+use std::str::FromStr;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    type Input = Sif;
+    type Part1 = usize;
+    type Part2 = String;
+
+    const PARAMS: &'static [&'static str] = &["width", "height", "palette"];
+
+    fn parse(input: &str) -> AdventResult<Sif> {
+        Self::parse_with(input, &Params::new())
+    }
+
+    fn parse_with(input: &str, params: &Params) -> AdventResult<Sif> {
+        let data = parse_input::<String>(input)?.first_element()?;
+        Ok(Sif::new(
+            Layers::decode(&data, params.get("width", 25)?, params.get("height", 6)?)?,
+            params.get("palette", Palette::default())?,
+        ))
+    }
+
+    fn part1(sif: &Sif) -> AdventResult<usize> {
+        solve_part1(&sif.layers)
+    }
+
+    fn part2(sif: &Sif) -> AdventResult<String> {
+        sif.recognize()
+    }
+
+    fn auxiliary(sif: &Sif) -> AdventResult<Auxiliary> {
+        let mut auxiliary = Auxiliary::new();
+        auxiliary.insert("picture".to_string(), sif.text()?.into());
+        Ok(auxiliary)
+    }
+}
+
+/// Layer with the fewest 0 digits, number of 1 digits times number of 2 digits on it.
+pub fn solve_part1(layers: &Layers) -> AdventResult<usize> {
+    layers
+        .stats()
+        .into_iter()
+        .min_by_key(|stats| stats.count(0))
+        .map(|stats| stats.count(1) * stats.count(2))
+        .ok_or(AdventError::EmptyInput)
+}
+
+/// Space Image Format data: layers of `width` x `height` digits, the first one on top.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Layers {
+    width: usize,
+    height: usize,
+    pixels: Vec<u8>,
+}
+
+impl Layers {
+    /// Fails unless `data` is digits splitting into whole layers.
+    pub fn decode(data: &str, width: usize, height: usize) -> AdventResult<Self> {
+        let layer_size = width * height;
+        if layer_size == 0 || data.is_empty() || !data.len().is_multiple_of(layer_size) {
+            return Err(AdventError::InvalidValue);
+        }
+        let pixels = data
+            .chars()
+            .map(|c| c.to_digit(10).map(|d| d as u8))
+            .collect::<Option<_>>()
+            .ok_or(AdventError::InvalidValue)?;
+        Ok(Self {
+            width,
+            height,
+            pixels,
+        })
+    }
+
+    /// Fails unless there is at least one layer, they all have the same size and hold digits.
+    pub fn from_grids(grids: &[Grid<u8>]) -> AdventResult<Self> {
+        let first = grids.first().ok_or(AdventError::EmptyInput)?;
+        let (width, height) = (first.width(), first.height());
+        let mut pixels = Vec::with_capacity(width * height * grids.len());
+        for grid in grids {
+            if grid.width() != width || grid.height() != height {
+                return Err(AdventError::InvalidValue);
+            }
+            pixels.extend(grid.values().copied());
+        }
+        if width * height == 0 || pixels.iter().any(|&pixel| pixel > 9) {
+            return Err(AdventError::InvalidValue);
+        }
+        Ok(Self {
+            width,
+            height,
+            pixels,
+        })
+    }
+
+    pub fn encode(&self) -> String {
+        self.to_string()
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn len(&self) -> usize {
+        self.pixels.len() / (self.width * self.height)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pixels.is_empty()
+    }
+
+    pub fn layer(&self, index: usize) -> Option<&[u8]> {
+        self.layers().nth(index)
+    }
+
+    pub fn layers(&self) -> impl Iterator<Item = &[u8]> {
+        self.pixels.chunks_exact(self.width * self.height)
+    }
+
+    pub fn stats(&self) -> Vec<LayerStats> {
+        self.layers().map(LayerStats::new).collect()
+    }
+
+    /// Topmost pixel the palette shows at every position, `None` where all layers are
+    /// transparent. Fails on digits missing from the palette.
+    pub fn merge(&self, palette: &Palette) -> AdventResult<Grid<Option<u8>>> {
+        let mut merged = vec![None; self.width * self.height];
+        for layer in self.layers() {
+            for (merged, &pixel) in merged.iter_mut().zip(layer) {
+                let shown = palette.shows(pixel)?;
+                if merged.is_none() && shown {
+                    *merged = Some(pixel);
+                }
+            }
+        }
+        Grid::from_vec(self.width, merged)
+    }
+}
+
+impl fmt::Display for Layers {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.pixels
+            .iter()
+            .try_for_each(|&pixel| write!(f, "{}", pixel))
+    }
+}
+
+/// How many times each digit appears on a layer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LayerStats {
+    counts: [usize; 10],
+}
+
+impl LayerStats {
+    fn new(layer: &[u8]) -> Self {
+        let mut counts = [0; 10];
+        for &pixel in layer {
+            counts[usize::from(pixel)] += 1;
+        }
+        Self { counts }
+    }
+
+    pub fn count(&self, digit: u8) -> usize {
+        self.counts.get(usize::from(digit)).copied().unwrap_or(0)
+    }
+}
+
+/// Colour of each digit, `None` for transparent ones. The digit 0 is the background, every other
+/// digit draws ink when reading letters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Palette {
+    colours: Vec<Option<Rgb>>,
+}
+
+impl Default for Palette {
+    /// Black, white and transparent.
+    fn default() -> Self {
+        Self {
+            colours: vec![Some(render::BLACK), Some(render::WHITE), None],
+        }
+    }
+}
+
+impl Palette {
+    /// Fails unless there are between 1 and 10 colours.
+    pub fn new(colours: Vec<Option<Rgb>>) -> AdventResult<Self> {
+        if colours.is_empty() || colours.len() > 10 {
+            return Err(AdventError::InvalidValue);
+        }
+        Ok(Self { colours })
+    }
+
+    pub fn len(&self) -> usize {
+        self.colours.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.colours.is_empty()
+    }
+
+    pub fn colour(&self, digit: u8) -> AdventResult<Option<Rgb>> {
+        self.colours
+            .get(usize::from(digit))
+            .copied()
+            .ok_or(AdventError::InvalidValue)
+    }
+
+    fn shows(&self, digit: u8) -> AdventResult<bool> {
+        Ok(self.colour(digit)?.is_some())
+    }
+}
+
+/// Comma separated colours as `rrggbb` hex, `-` for transparent: `000000,ffffff,-`.
+impl FromStr for Palette {
+    type Err = AdventError;
+
+    fn from_str(s: &str) -> AdventResult<Self> {
+        let colour = |entry: &str| -> AdventResult<Option<Rgb>> {
+            if entry == "-" {
+                return Ok(None);
+            }
+            if entry.len() != 6 || !entry.bytes().all(|b| b.is_ascii_hexdigit()) {
+                return Err(AdventError::InvalidValue);
+            }
+            let rgb = u32::from_str_radix(entry, 16).map_err(|_| AdventError::InvalidValue)?;
+            let [_, r, g, b] = rgb.to_be_bytes();
+            Ok(Some([r, g, b]))
+        };
+        Self::new(
+            s.split(',')
+                .map(str::trim)
+                .map(colour)
+                .collect::<AdventResult<_>>()?,
+        )
+    }
+}
+
+/// Layers and the palette they are shown with. Digits missing from the palette only fail once
+/// the picture is decoded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sif {
+    pub layers: Layers,
+    pub palette: Palette,
+}
+
+impl Sif {
+    pub fn new(layers: Layers, palette: Palette) -> Self {
+        Self { layers, palette }
+    }
+
+    /// Visible digit at every position, `None` where all layers are transparent.
+    pub fn merged(&self) -> AdventResult<Grid<Option<u8>>> {
+        self.layers.merge(&self.palette)
+    }
+
+    /// Ink as `*`, background and transparent pixels as spaces.
+    pub fn text(&self) -> AdventResult<String> {
+        Ok(render::text(
+            &self.ink()?,
+            |&ink| if ink { '*' } else { ' ' },
+        ))
+    }
+
+    pub fn recognize(&self) -> AdventResult<String> {
+        ocr::recognize(&self.ink()?)
+    }
+
+    /// Pixels in their palette colour, grey where all layers are transparent.
+    pub fn to_image(&self, scale: usize) -> AdventResult<render::Image> {
+        let merged = self.merged()?;
+        let colours = merged
+            .values()
+            .map(|pixel| match pixel {
+                Some(digit) => Ok(self.palette.colour(*digit)?.unwrap_or(render::GREY)),
+                None => Ok(render::GREY),
+            })
+            .collect::<AdventResult<Vec<Rgb>>>()?;
+        let colours = Grid::from_vec(merged.width(), colours)?;
+        Ok(render::Image::from_grid(&colours, scale, |&colour| colour))
+    }
+
+    fn ink(&self) -> AdventResult<Grid<bool>> {
+        Ok(self
+            .merged()?
+            .map(|pixel| matches!(pixel, Some(digit) if *digit != 0)))
+    }
+}
+
+#[test]
+fn test_day08_layers() {
+    let layers = Layers::decode("123456789012", 3, 2).unwrap();
+    assert_eq!(layers.len(), 2);
+    assert_eq!(layers.layer(1), Some(&[7, 8, 9, 0, 1, 2][..]));
+    assert_eq!(solve_part1(&layers).unwrap(), 1);
+    assert_eq!(layers.stats()[1].count(0), 1);
+    assert_eq!(layers.encode(), "123456789012");
+    assert!(Layers::decode("1234567890", 3, 2).is_err());
+    assert!(Layers::decode("123456", 0, 2).is_err());
+    assert!(Layers::decode("12345x", 3, 2).is_err());
+}
+
+#[test]
+fn test_day08_encode_round_trip() {
+    let top = Grid::from_rows(vec![vec![2, 1], vec![2, 2]]).unwrap();
+    let bottom = Grid::from_rows(vec![vec![0, 0], vec![1, 0]]).unwrap();
+    let layers = Layers::from_grids(&[top, bottom]).unwrap();
+    assert_eq!(layers.encode(), "21220010");
+    assert_eq!(Layers::decode(&layers.encode(), 2, 2).unwrap(), layers);
+
+    let small = Grid::new(1, 1, 0);
+    assert!(Layers::from_grids(&[Grid::new(2, 2, 0), small]).is_err());
+    assert!(Layers::from_grids(&[Grid::new(1, 1, 10)]).is_err());
+    assert!(Layers::from_grids(&[]).is_err());
+}
+
+#[test]
+fn test_day08_decode() {
+    let layers = Layers::decode("0222112222120000", 2, 2).unwrap();
+    let sif = Sif::new(layers, Palette::default());
+    assert_eq!(sif.text().unwrap(), " *\n* ");
+    let image = sif.to_image(1).unwrap();
+    assert_eq!(
+        image.pixels(),
+        &[render::BLACK, render::WHITE, render::WHITE, render::BLACK]
+    );
+}
+
+#[test]
+fn test_day08_palette() {
+    assert_eq!(
+        "000000,ffffff,-".parse::<Palette>().unwrap(),
+        Palette::default()
+    );
+    assert!("".parse::<Palette>().is_err());
+    assert!("fffff".parse::<Palette>().is_err());
+    assert!("+12345".parse::<Palette>().is_err());
+    assert!("-,-,-,-,-,-,-,-,-,-,-".parse::<Palette>().is_err());
+
+    // Four colours with 3 transparent instead of 2
+    let palette: Palette = "000000,ffffff,dc322f,-".parse().unwrap();
+    let layers = Layers::decode("33211002", 2, 2).unwrap();
+    let sif = Sif::new(layers.clone(), palette);
+    assert_eq!(
+        sif.merged().unwrap(),
+        Grid::from_vec(2, vec![Some(1), Some(0), Some(2), Some(1)]).unwrap()
+    );
+    assert_eq!(sif.text().unwrap(), "* \n**");
+    assert_eq!(sif.to_image(1).unwrap().pixels()[2], render::RED);
+
+    // 3 is not in the default palette
+    assert!(Sif::new(layers, Palette::default()).merged().is_err());
+    let transparent = Layers::decode("22", 1, 1).unwrap();
+    let sif = Sif::new(transparent, Palette::default());
+    assert_eq!(
+        sif.merged().unwrap(),
+        Grid::from_vec(1, vec![None]).unwrap()
+    );
+}
@@ -0,0 +1,49 @@
+use crate::intcode::run_program;
+use crate::solution::Solution;
+use crate::{parse_input, AdventError, AdventResult};
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    type Input = Vec<isize>;
+    type Part1 = isize;
+    type Part2 = isize;
+
+    fn parse(input: &str) -> AdventResult<Vec<isize>> {
+        parse_input::<isize>(input)?.first_row()
+    }
+
+    // BOOST keycode
+    fn part1(code: &Vec<isize>) -> AdventResult<isize> {
+        let res = run_program(code, &[1]);
+        res.first().copied().ok_or(AdventError::InvalidValue)
+    }
+
+    // Distress signal coordinates
+    fn part2(code: &Vec<isize>) -> AdventResult<isize> {
+        let res = run_program(code, &[2]);
+        res.first().copied().ok_or(AdventError::InvalidValue)
+    }
+}
+
+#[test]
+fn test_case_day9_quining() {
+    let program = &[
+        109, 1, 204, -1, 1001, 100, 1, 100, 1008, 100, 16, 101, 1006, 101, 0, 99,
+    ];
+    assert_eq!(run_program(program, &[]), program);
+}
+
+#[test]
+fn test_case_day9_16_digits() {
+    let program = &[1102, 34_915_192, 34_915_192, 7, 4, 7, 99, 0];
+    let res = run_program(program, &[]).last().unwrap() / 1_000_000_000_000_000;
+    assert!(0 < res && res < 10);
+}
+
+#[test]
+fn test_case_day9_big_number() {
+    let program = &[104, 1_125_899_906_842_624, 99];
+    assert_eq!(run_program(program, &[]), vec![1_125_899_906_842_624]);
+}
@@ -0,0 +1,566 @@
+use crate::geometry::{Direction, Point};
+use crate::grid::Grid;
+use crate::parallel::Pool;
+use crate::solution::{Auxiliary, Params, Solution};
+use crate::{AdventError, AdventResult};
+use num::integer::gcd;
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashSet};
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    type Input = Survey;
+    type Part1 = usize;
+    type Part2 = isize;
+
+    const PARAMS: &'static [&'static str] = &["station", "start", "rotation", "nth"];
+
+    fn parse(input: &str) -> AdventResult<Survey> {
+        Self::parse_with(input, &Params::new())
+    }
+
+    fn parse_with(input: &str, params: &Params) -> AdventResult<Survey> {
+        let station = params
+            .get_str("station")
+            .map(|station| {
+                parse_point(station).ok_or_else(|| AdventError::InvalidParam("station".to_string()))
+            })
+            .transpose()?;
+        Ok(Survey {
+            asteroids: map_to_asteroid_vec(&read_map(input)?),
+            station,
+            start: params.get("start", Bearing::from(Direction::Up))?,
+            rotation: params.get("rotation", Rotation::Clockwise)?,
+            nth: params.get("nth", 200)?,
+        })
+    }
+
+    fn part1(survey: &Survey) -> AdventResult<usize> {
+        solve_part1(&survey.asteroids)
+    }
+
+    fn part2(survey: &Survey) -> AdventResult<isize> {
+        solve_part2(survey)
+    }
+
+    fn auxiliary(survey: &Survey) -> AdventResult<Auxiliary> {
+        let rotations: Vec<usize> = survey
+            .laser()?
+            .rotations(&survey.asteroids)
+            .iter()
+            .map(Vec::len)
+            .collect();
+        let mut auxiliary = Auxiliary::new();
+        auxiliary.insert("rotations".to_string(), rotations.into());
+        Ok(auxiliary)
+    }
+}
+
+/// The asteroids and how the laser sweeps them: from `station`, the best asteroid when not
+/// given, starting along `start` and turning in the `rotation` sense until the `nth` one.
+pub struct Survey {
+    pub asteroids: Vec<AsteroidVector>,
+    pub station: Option<AsteroidVector>,
+    pub start: Bearing,
+    pub rotation: Rotation,
+    pub nth: usize,
+}
+
+impl Survey {
+    pub fn laser(&self) -> AdventResult<Laser> {
+        if self.asteroids.is_empty() {
+            return Err(AdventError::EmptyInput);
+        }
+        let station = match self.station {
+            Some(station) => station,
+            None => *best_asteroid(&self.asteroids).1,
+        };
+        Ok(Laser::new(station)
+            .start(self.start)
+            .rotation(self.rotation))
+    }
+}
+
+pub fn solve_part1(asteroids: &[AsteroidVector]) -> AdventResult<usize> {
+    if asteroids.is_empty() {
+        return Err(AdventError::EmptyInput);
+    }
+    let (visible_asteroids, _) = best_asteroid(asteroids);
+    Ok(visible_asteroids)
+}
+
+/// Fails when fewer than `nth` asteroids get vaporized.
+pub fn solve_part2(survey: &Survey) -> AdventResult<isize> {
+    let res = survey
+        .laser()?
+        .vaporized(&survey.asteroids, survey.nth)
+        .ok_or(AdventError::InvalidValue)?;
+    Ok(res.x * 100 + res.y)
+}
+
+pub type AsteroidVector = Point<isize>;
+
+fn read_map(asteroid_map: &str) -> AdventResult<Grid<bool>> {
+    Grid::parse_with(asteroid_map, |c| match c {
+        '#' => Ok(true),
+        '.' => Ok(false),
+        _ => Err(AdventError::InvalidValue),
+    })
+}
+
+fn map_to_asteroid_vec(asteroid_map: &Grid<bool>) -> Vec<AsteroidVector> {
+    asteroid_map
+        .iter()
+        .filter(|(_, &is_asteroid)| is_asteroid)
+        .map(|(p, _)| p.signed())
+        .collect()
+}
+
+/// Direction from one asteroid to another, reduced by the gcd of its coordinates so that
+/// asteroids on the same line of sight share it. Ordered clockwise starting from up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bearing(Point<isize>);
+
+impl Bearing {
+    /// The bearing of `to` seen from `from` and how many whole steps along it `to` is, `None`
+    /// when both are the same point.
+    pub fn between(from: AsteroidVector, to: AsteroidVector) -> Option<(Bearing, isize)> {
+        let vector = to - from;
+        let steps = gcd(vector.x, vector.y);
+        if steps == 0 {
+            return None;
+        }
+        Some((
+            Bearing(Point::new(vector.x / steps, vector.y / steps)),
+            steps,
+        ))
+    }
+
+    /// `None` for the zero vector.
+    pub fn new(direction: Point<isize>) -> Option<Bearing> {
+        Self::between(Point::new(0, 0), direction).map(|(bearing, _)| bearing)
+    }
+
+    pub fn direction(&self) -> Point<isize> {
+        self.0
+    }
+
+    /// The same bearing mirrored left to right, turning clockwise sweeps into counterclockwise ones.
+    fn mirror(self) -> Bearing {
+        Bearing(Point::new(-self.0.x, self.0.y))
+    }
+
+    /// 0 from up (included) to down (excluded) going clockwise, 1 for the other half.
+    fn half(&self) -> u8 {
+        let Point { x, y } = self.0;
+        if x > 0 || (x == 0 && y < 0) {
+            0
+        } else {
+            1
+        }
+    }
+}
+
+impl Ord for Bearing {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Within a half turn, `other` is clockwise from `self` when the cross product is positive
+        // (y grows downwards)
+        let cross = self.0.x * other.0.y - self.0.y * other.0.x;
+        self.half().cmp(&other.half()).then_with(|| 0.cmp(&cross))
+    }
+}
+
+impl From<Direction> for Bearing {
+    fn from(direction: Direction) -> Bearing {
+        Bearing(direction.offset())
+    }
+}
+
+/// A direction letter or arrow like `U` or `^`, or a vector as `x,y` with y growing downwards.
+impl FromStr for Bearing {
+    type Err = AdventError;
+
+    fn from_str(s: &str) -> AdventResult<Bearing> {
+        let mut chars = s.chars();
+        if let (Some(c), None) = (chars.next(), chars.next()) {
+            return Ok(Direction::try_from(c)?.into());
+        }
+        parse_point(s)
+            .and_then(Bearing::new)
+            .ok_or(AdventError::InvalidValue)
+    }
+}
+
+impl PartialOrd for Bearing {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn visible_asteroids_count(asteroids: &[AsteroidVector], station: &AsteroidVector) -> usize {
+    asteroids
+        .iter()
+        .filter_map(|&asteroid| Bearing::between(*station, asteroid))
+        .map(|(bearing, _)| bearing)
+        .collect::<HashSet<_>>()
+        .len()
+}
+
+/// Ties go to the largest position, whatever the order the counts were computed in.
+fn best_asteroid(asteroids: &[AsteroidVector]) -> (usize, &AsteroidVector) {
+    let counts = Pool::default().map(asteroids, |asteroid| {
+        visible_asteroids_count(asteroids, asteroid)
+    });
+    counts.into_iter().zip(asteroids).max().expect("Asteroids!")
+}
+
+/// Asteroids along each bearing from `station`, closest first, the bearings clockwise from up.
+fn lines_of_sight(
+    asteroids: &[AsteroidVector],
+    station: AsteroidVector,
+) -> BTreeMap<Bearing, Vec<AsteroidVector>> {
+    let mut lines: BTreeMap<Bearing, Vec<(isize, AsteroidVector)>> = BTreeMap::new();
+    for &asteroid in asteroids {
+        if let Some((bearing, steps)) = Bearing::between(station, asteroid) {
+            lines.entry(bearing).or_default().push((steps, asteroid));
+        }
+    }
+    lines
+        .into_iter()
+        .map(|(bearing, mut line)| {
+            line.sort_unstable();
+            (
+                bearing,
+                line.into_iter().map(|(_, asteroid)| asteroid).collect(),
+            )
+        })
+        .collect()
+}
+
+/// `x,y`
+fn parse_point(s: &str) -> Option<AsteroidVector> {
+    let (x, y) = s.split_once(',')?;
+    Some(Point::new(x.trim().parse().ok()?, y.trim().parse().ok()?))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rotation {
+    Clockwise,
+    Counterclockwise,
+}
+
+impl FromStr for Rotation {
+    type Err = AdventError;
+
+    fn from_str(s: &str) -> AdventResult<Rotation> {
+        match s {
+            "clockwise" | "cw" => Ok(Rotation::Clockwise),
+            "counterclockwise" | "ccw" => Ok(Rotation::Counterclockwise),
+            _ => Err(AdventError::InvalidValue),
+        }
+    }
+}
+
+/// A laser on `station` vaporizing one asteroid per line of sight each time it sweeps past,
+/// the closest first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Laser {
+    station: AsteroidVector,
+    start: Bearing,
+    rotation: Rotation,
+}
+
+impl Laser {
+    /// Pointing up and turning clockwise, as in the puzzle.
+    pub fn new(station: AsteroidVector) -> Self {
+        Self {
+            station,
+            start: Direction::Up.into(),
+            rotation: Rotation::Clockwise,
+        }
+    }
+
+    /// Asteroids straight along `start` are the first ones hit.
+    pub fn start(mut self, start: Bearing) -> Self {
+        self.start = start;
+        self
+    }
+
+    pub fn rotation(mut self, rotation: Rotation) -> Self {
+        self.rotation = rotation;
+        self
+    }
+
+    /// The asteroids vaporized during each full turn, in order. The station itself is spared.
+    pub fn rotations(&self, asteroids: &[AsteroidVector]) -> Vec<Vec<AsteroidVector>> {
+        let mut lines: Vec<(Bearing, Vec<AsteroidVector>)> =
+            lines_of_sight(asteroids, self.station)
+                .into_iter()
+                .collect();
+        // Bearings are ordered clockwise from up, the sweep goes on from there past `start`
+        let sweep = |bearing: Bearing| match self.rotation {
+            Rotation::Clockwise => bearing,
+            Rotation::Counterclockwise => bearing.mirror(),
+        };
+        let start = sweep(self.start);
+        lines.sort_by_key(|&(bearing, _)| {
+            let bearing = sweep(bearing);
+            (bearing < start, bearing)
+        });
+        let turns = lines.iter().map(|(_, line)| line.len()).max().unwrap_or(0);
+        (0..turns)
+            .map(|turn| {
+                lines
+                    .iter()
+                    .filter_map(|(_, line)| line.get(turn).copied())
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Every asteroid but the station, in the order they are vaporized.
+    pub fn order(&self, asteroids: &[AsteroidVector]) -> Vec<AsteroidVector> {
+        self.rotations(asteroids).into_iter().flatten().collect()
+    }
+
+    /// The `nth` asteroid vaporized, counting from 1. `None` when fewer get vaporized.
+    pub fn vaporized(&self, asteroids: &[AsteroidVector], nth: usize) -> Option<AsteroidVector> {
+        self.order(asteroids).get(nth.checked_sub(1)?).copied()
+    }
+}
+
+#[test]
+fn test_day10_case1() {
+    let asteroids = &[".#..#", ".....", "#####", "....#", "...##"];
+    let map = read_map(&asteroids.join("\n")).unwrap();
+    let asteroids = map_to_asteroid_vec(&map);
+    assert_eq!(
+        best_asteroid(&asteroids),
+        (8, &AsteroidVector { x: 3, y: 4 })
+    )
+}
+
+#[test]
+fn test_day10_case2() {
+    let asteroids = &[
+        "......#.#.",
+        "#..#.#....",
+        "..#######.",
+        ".#.#.###..",
+        ".#..#.....",
+        "..#....#.#",
+        "#..#....#.",
+        ".##.#..###",
+        "##...#..#.",
+        ".#....####",
+    ];
+    let map = read_map(&asteroids.join("\n")).unwrap();
+    let asteroids = map_to_asteroid_vec(&map);
+    assert_eq!(
+        best_asteroid(&asteroids),
+        (33, &AsteroidVector { x: 5, y: 8 })
+    )
+}
+
+#[test]
+fn test_day10_case3() {
+    let asteroids = &[
+        ".#..#..###",
+        "####.###.#",
+        "....###.#.",
+        "..###.##.#",
+        "##.##.#.#.",
+        "....###..#",
+        "..#.#..#.#",
+        "#..#.#.###",
+        ".##...##.#",
+        ".....#.#..",
+    ];
+    let map = read_map(&asteroids.join("\n")).unwrap();
+    let asteroids = map_to_asteroid_vec(&map);
+    assert_eq!(
+        best_asteroid(&asteroids),
+        (41, &AsteroidVector { x: 6, y: 3 })
+    )
+}
+
+#[test]
+fn test_day10_case4() {
+    let asteroids = &[
+        ".#..##.###...#######",
+        "##.############..##.",
+        ".#.######.########.#",
+        ".###.#######.####.#.",
+        "#####.##.#.##.###.##",
+        "..#####..#.#########",
+        "####################",
+        "#.####....###.#.#.##",
+        "##.#################",
+        "#####.##.###..####..",
+        "..######..##.#######",
+        "####.##.####...##..#",
+        ".#####..#.######.###",
+        "##...#.##########...",
+        "#.##########.#######",
+        ".####.#.###.###.#.##",
+        "....##.##.###..#####",
+        ".#.#.###########.###",
+        "#.#.#.#####.####.###",
+        "###.##.####.##.#..##",
+    ];
+    let map = read_map(&asteroids.join("\n")).unwrap();
+    let asteroids = map_to_asteroid_vec(&map);
+    assert_eq!(
+        best_asteroid(&asteroids),
+        (210, &AsteroidVector { x: 11, y: 13 })
+    )
+}
+
+#[test]
+fn test_day10_part2() {
+    let asteroids = &[
+        ".#..##.###...#######",
+        "##.############..##.",
+        ".#.######.########.#",
+        ".###.#######.####.#.",
+        "#####.##.#.##.###.##",
+        "..#####..#.#########",
+        "####################",
+        "#.####....###.#.#.##",
+        "##.#################",
+        "#####.##.###..####..",
+        "..######..##.#######",
+        "####.##.####...##..#",
+        ".#####..#.######.###",
+        "##...#.##########...",
+        "#.##########.#######",
+        ".####.#.###.###.#.##",
+        "....##.##.###..#####",
+        ".#.#.###########.###",
+        "#.#.#.#####.####.###",
+        "###.##.####.##.#..##",
+    ];
+    let map = read_map(&asteroids.join("\n")).unwrap();
+    let asteroids = map_to_asteroid_vec(&map);
+    let center = AsteroidVector { x: 11, y: 13 };
+    let hits = Laser::new(center).order(&asteroids);
+    assert_eq!(hits[0], AsteroidVector { x: 11, y: 12 });
+    assert_eq!(hits[1], AsteroidVector { x: 12, y: 1 });
+    assert_eq!(hits[2], AsteroidVector { x: 12, y: 2 });
+    assert_eq!(hits[9], AsteroidVector { x: 12, y: 8 });
+    assert_eq!(hits[19], AsteroidVector { x: 16, y: 0 });
+    assert_eq!(hits[49], AsteroidVector { x: 16, y: 9 });
+    assert_eq!(hits[99], AsteroidVector { x: 10, y: 16 });
+    assert_eq!(hits[198], AsteroidVector { x: 9, y: 6 });
+    assert_eq!(hits[199], AsteroidVector { x: 8, y: 2 });
+    assert_eq!(hits[200], AsteroidVector { x: 10, y: 9 });
+    assert_eq!(hits[298], AsteroidVector { x: 11, y: 1 });
+}
+
+#[test]
+fn test_day10_bearing_order() {
+    let station = Point::new(0, 0);
+    let bearing = |x, y| Bearing::between(station, Point::new(x, y)).unwrap().0;
+    // Clockwise from up, including directions too close for a rounded angle to tell apart
+    let clockwise = [
+        bearing(0, -1),
+        bearing(1, -1000),
+        bearing(1, -999),
+        bearing(1, 0),
+        bearing(1, 1),
+        bearing(0, 1),
+        bearing(-1, 1),
+        bearing(-1, 0),
+        bearing(-1000, -1),
+        bearing(-1, -1000),
+    ];
+    assert!(clockwise.windows(2).all(|pair| pair[0] < pair[1]));
+    assert_eq!(bearing(4, -6), bearing(2, -3));
+    assert_eq!(
+        Bearing::between(station, Point::new(4, -6)),
+        Some((Bearing(Point::new(2, -3)), 2))
+    );
+    assert_eq!(Bearing::between(station, station), None);
+}
+
+#[test]
+fn test_day10_vaporization_small() {
+    let map = [
+        ".#....#####...#..",
+        "##...##.#####..##",
+        "##...#...#.#####.",
+        "..#.....X...###..",
+        "..#.#.....#....##",
+    ];
+    let map = read_map(&map.join("\n").replace('X', "#")).unwrap();
+    let asteroids = map_to_asteroid_vec(&map);
+    let order = Laser::new(Point::new(8, 3)).order(&asteroids);
+    assert_eq!(order.len(), asteroids.len() - 1);
+    assert_eq!(
+        order[..9],
+        [
+            Point::new(8, 1),
+            Point::new(9, 0),
+            Point::new(9, 1),
+            Point::new(10, 0),
+            Point::new(9, 2),
+            Point::new(11, 1),
+            Point::new(12, 1),
+            Point::new(11, 2),
+            Point::new(15, 1),
+        ]
+    );
+    assert_eq!(order.last(), Some(&Point::new(14, 3)));
+}
+
+#[test]
+fn test_day10_laser_configuration() {
+    // Station in the middle, one asteroid on each axis and two on the diagonal up-left
+    let map = ["#.#..", "..#..", "..#.#", ".....", "..#.."];
+    let asteroids = map_to_asteroid_vec(&read_map(&map.join("\n")).unwrap());
+    let station = Point::new(2, 2);
+    let up = Point::new(2, 1);
+    let far_up = Point::new(2, 0);
+    let right = Point::new(4, 2);
+    let down = Point::new(2, 4);
+    let up_left = Point::new(0, 0);
+
+    let laser = Laser::new(station);
+    assert_eq!(
+        laser.rotations(&asteroids),
+        vec![vec![up, right, down, up_left], vec![far_up]]
+    );
+    assert_eq!(laser.vaporized(&asteroids, 1), Some(up));
+    assert_eq!(laser.vaporized(&asteroids, 5), Some(far_up));
+    assert_eq!(laser.vaporized(&asteroids, 6), None);
+    assert_eq!(laser.vaporized(&asteroids, 0), None);
+
+    let laser = laser.rotation(Rotation::Counterclockwise);
+    assert_eq!(
+        laser.order(&asteroids),
+        vec![up, up_left, down, right, far_up]
+    );
+    let laser = laser.start(Direction::Down.into());
+    assert_eq!(
+        laser.order(&asteroids),
+        vec![down, right, up, up_left, far_up]
+    );
+    let laser = Laser::new(station).start("-1,-1".parse().unwrap());
+    assert_eq!(
+        laser.order(&asteroids),
+        vec![up_left, up, right, down, far_up]
+    );
+
+    // Stations need not be the best asteroid, nor an asteroid at all
+    let laser = Laser::new(Point::new(2, 3));
+    assert_eq!(laser.order(&asteroids)[..2], [station, right]);
+    assert_eq!(laser.order(&asteroids).len(), asteroids.len());
+
+    assert!("0,0".parse::<Bearing>().is_err());
+    assert!("x".parse::<Bearing>().is_err());
+    assert!("sideways".parse::<Rotation>().is_err());
+}
@@ -0,0 +1,131 @@
+use crate::geometry::{Direction, Point};
+use crate::grid::{Grid, SparseGrid};
+use crate::intcode::IntCode;
+use crate::render::{self, Frame, Recorder, Rgb};
+use crate::solution::{Auxiliary, Solution};
+use crate::{ocr, parse_input, AdventResult};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    type Input = Vec<isize>;
+    type Part1 = usize;
+    type Part2 = String;
+
+    fn parse(input: &str) -> AdventResult<Vec<isize>> {
+        parse_input::<isize>(input)?.first_row()
+    }
+
+    fn part1(program: &Vec<isize>) -> AdventResult<usize> {
+        Ok(tiles_painted(program))
+    }
+
+    fn part2(program: &Vec<isize>) -> AdventResult<String> {
+        read_registration(program)
+    }
+
+    fn auxiliary(program: &Vec<isize>) -> AdventResult<Auxiliary> {
+        let mut auxiliary = Auxiliary::new();
+        auxiliary.insert("picture".to_string(), render_painting(program).into());
+        Ok(auxiliary)
+    }
+
+    fn record(program: &Vec<isize>, recorder: &mut Recorder) -> AdventResult<()> {
+        let mut robot = PaintRobot::new(program);
+        robot.run_with(1, |robot| recorder.capture(robot));
+        Ok(())
+    }
+}
+
+struct PaintRobot {
+    intcode: IntCode,
+    hull: SparseGrid<isize>,
+    position: Point<isize>,
+    direction: Direction,
+}
+
+impl PaintRobot {
+    fn new(program: &[isize]) -> Self {
+        PaintRobot {
+            intcode: IntCode::new(program),
+            hull: SparseGrid::new(),
+            position: Point::default(),
+            direction: Direction::Up,
+        }
+    }
+
+    fn paint_hull(&mut self, color: isize) {
+        match color {
+            0 => {
+                self.hull.insert(self.position, 0);
+            }
+            1 => {
+                self.hull.insert(self.position, 1);
+            }
+            _ => panic!("Unexpected color!"),
+        }
+    }
+
+    fn run(&mut self, init_color: isize) {
+        self.run_with(init_color, |_| ())
+    }
+
+    /// Runs the robot, calling `on_step` after every move.
+    fn run_with<F: FnMut(&Self)>(&mut self, init_color: isize, mut on_step: F) {
+        let mut color = init_color;
+        while !self.intcode.has_halted() {
+            color = self.intcode.run_till_output(&[color]);
+            self.paint_hull(color);
+            let dir = self.intcode.run_till_output(&[]);
+            self.turn_and_move(dir);
+            color = *self.hull.get(self.position).unwrap_or(&0);
+            on_step(self);
+        }
+    }
+
+    fn turn_and_move(&mut self, input: isize) {
+        self.direction = match input {
+            0 => self.direction.turn_left(),
+            1 => self.direction.turn_right(),
+            _ => panic!("Unexpected direction!"),
+        };
+        self.position += self.direction.offset();
+    }
+}
+
+impl Frame for PaintRobot {
+    fn frame(&self) -> Grid<Rgb> {
+        // The robot is drawn as colour 2
+        let mut hull = self.hull.clone();
+        hull.insert(self.position, 2);
+        let (hull, _) = hull.to_grid(0);
+        hull.map(|&color| match color {
+            0 => render::BLACK,
+            1 => render::WHITE,
+            _ => render::RED,
+        })
+    }
+}
+
+pub fn tiles_painted(program: &[isize]) -> usize {
+    let mut robot = PaintRobot::new(program);
+    robot.run(0);
+    robot.hull.len()
+}
+
+fn paint_registration(program: &[isize]) -> SparseGrid<isize> {
+    let mut robot = PaintRobot::new(program);
+    robot.run(1);
+    robot.hull
+}
+
+pub fn render_painting(program: &[isize]) -> String {
+    let (hull, _) = paint_registration(program).to_grid(0);
+    render::text(&hull, |&color| if color == 1 { '#' } else { ' ' })
+}
+
+pub fn read_registration(program: &[isize]) -> AdventResult<String> {
+    let (hull, _) = paint_registration(program).to_grid(0);
+    ocr::recognize(&hull.map(|&color| color == 1))
+}
@@ -1,42 +1,76 @@
-use advent2019::AdventResult;
+use crate::solution::{Params, Solution};
+use crate::{AdventError, AdventResult};
 use num::integer::lcm;
+use regex::Regex;
 use std::cmp::Ordering;
 use std::fmt;
 use std::ops::{Add, AddAssign};
 
-fn main() -> AdventResult<()> {
-    // Just used to download the file
-    let _ = advent2019::get_input::<String>(12);
-    solve_part1()?;
-    solve_part2()?;
-    Ok(())
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    type Input = Scan;
+    type Part1 = isize;
+    type Part2 = usize;
+
+    const PARAMS: &'static [&'static str] = &["steps"];
+
+    fn parse(input: &str) -> AdventResult<Scan> {
+        Self::parse_with(input, &Params::new())
+    }
+
+    fn parse_with(input: &str, params: &Params) -> AdventResult<Scan> {
+        Ok(Scan {
+            system: parse_system(input)?,
+            steps: params.get("steps", 1000)?,
+        })
+    }
+
+    fn part1(scan: &Scan) -> AdventResult<isize> {
+        solve_part1(&scan.system, scan.steps)
+    }
+
+    fn part2(scan: &Scan) -> AdventResult<usize> {
+        solve_part2(&scan.system)
+    }
 }
 
-fn get_input() -> System {
-    System {
-        moons: [
-            Moon::new(&[-9, 10, -1]),
-            Moon::new(&[-14, -8, 14]),
-            Moon::new(&[1, 5, 6]),
-            Moon::new(&[-19, 7, 8]),
-        ],
+/// The moons' starting positions and how many steps part 1 simulates.
+pub struct Scan {
+    system: System,
+    steps: usize,
+}
+
+fn parse_system(input: &str) -> AdventResult<System> {
+    let re = Regex::new(r"^<x=(-?\d+), y=(-?\d+), z=(-?\d+)>$")?;
+    let moons = input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let caps = re.captures(line).ok_or(AdventError::InvalidValue)?;
+            Ok(Moon::new(&[
+                caps[1].parse()?,
+                caps[2].parse()?,
+                caps[3].parse()?,
+            ]))
+        })
+        .collect::<AdventResult<Vec<_>>>()?;
+    if moons.is_empty() {
+        return Err(AdventError::EmptyInput);
     }
+    Ok(System { moons })
 }
 
-pub fn solve_part1() -> AdventResult<()> {
-    let mut system = get_input();
-    n_steps(&mut system, 1000);
-    let total_energy = total_system_energy(&system);
-    println!("Total energy: {}", total_energy);
-    Ok(())
+pub fn solve_part1(system: &System, steps: usize) -> AdventResult<isize> {
+    let mut system = system.clone();
+    n_steps(&mut system, steps);
+    Ok(total_system_energy(&system))
 }
 
-pub fn solve_part2() -> AdventResult<()> {
-    let mut system = get_input();
-    n_steps(&mut system, 1000);
-    let cycle = cycle_length_lcm(&mut system);
-    println!("Cycle length: {}", cycle);
-    Ok(())
+pub fn solve_part2(system: &System) -> AdventResult<usize> {
+    Ok(cycle_length_lcm(&mut system.clone()))
 }
 
 fn total_system_energy(system: &System) -> isize {
@@ -135,8 +169,8 @@ impl AddAssign<&Vector> for Vector {
 }
 
 #[derive(PartialEq, Eq, Clone)]
-struct System {
-    moons: [Moon; 4],
+pub struct System {
+    moons: Vec<Moon>,
 }
 
 type Axis = Vec<(isize, isize)>;
@@ -223,7 +257,7 @@ impl Moon {
 #[test]
 fn test_day12_case1() {
     let mut moons = System {
-        moons: [
+        moons: vec![
             Moon::new(&[-1, 0, 2]),
             Moon::new(&[2, -10, -7]),
             Moon::new(&[4, -8, 8]),
@@ -237,7 +271,7 @@ fn test_day12_case1() {
 #[test]
 fn test_day12_case2() {
     let mut moons = System {
-        moons: [
+        moons: vec![
             Moon::new(&[-8, -10, 0]),
             Moon::new(&[5, 5, 10]),
             Moon::new(&[2, -7, 3]),
@@ -251,7 +285,7 @@ fn test_day12_case2() {
 #[test]
 fn test_day12_case1_part2() {
     let mut moons = System {
-        moons: [
+        moons: vec![
             Moon::new(&[-1, 0, 2]),
             Moon::new(&[2, -10, -7]),
             Moon::new(&[4, -8, 8]),
@@ -265,7 +299,7 @@ fn test_day12_case1_part2() {
 #[test]
 fn test_day12_case2_part2() {
     let mut moons = System {
-        moons: [
+        moons: vec![
             Moon::new(&[-8, -10, 0]),
             Moon::new(&[5, 5, 10]),
             Moon::new(&[2, -7, 3]),
@@ -274,3 +308,13 @@ fn test_day12_case2_part2() {
     };
     assert_eq!(cycle_length_lcm(&mut moons), 4_686_774_924);
 }
+
+#[test]
+fn test_day12_parse() {
+    let system = parse_system("<x=-1, y=0, z=2>\n<x=2, y=-10, z=-7>\n").unwrap();
+    assert_eq!(
+        system.moons,
+        vec![Moon::new(&[-1, 0, 2]), Moon::new(&[2, -10, -7])]
+    );
+    assert!(parse_system("<x=1, y=2>").is_err());
+}
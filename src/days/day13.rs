@@ -0,0 +1,128 @@
+use crate::geometry::Point;
+use crate::grid::{Grid, SparseGrid};
+use crate::intcode::{run_program, IntCode};
+use crate::render::{self, Frame, Recorder, Rgb};
+use crate::solution::Solution;
+use crate::tui::{Arcade, Session};
+use crate::{parse_input, AdventResult};
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    type Input = Vec<isize>;
+    type Part1 = usize;
+    type Part2 = isize;
+
+    fn parse(input: &str) -> AdventResult<Vec<isize>> {
+        parse_input::<isize>(input)?.first_row()
+    }
+
+    fn part1(program: &Vec<isize>) -> AdventResult<usize> {
+        let screen_input = run_program(program, &[]);
+        Ok(count_block_tiles(&screen_input))
+    }
+
+    fn part2(program: &Vec<isize>) -> AdventResult<isize> {
+        Ok(play_game(&mut program.to_vec()))
+    }
+
+    fn record(program: &Vec<isize>, recorder: &mut Recorder) -> AdventResult<()> {
+        let mut program = program.to_vec();
+        program[0] = 2;
+        BrickBreaker::new(&program).run_with(|game| recorder.capture(game));
+        Ok(())
+    }
+
+    fn console(program: &Vec<isize>) -> Option<Session> {
+        let mut program = program.to_vec();
+        program[0] = 2;
+        Some(Session::new(&program, Arcade::new()))
+    }
+}
+
+fn count_block_tiles(input: &[isize]) -> usize {
+    input
+        // (x, y, tile_id)
+        .chunks(3)
+        // tile id == 2
+        .filter(|s| s[2] == 2)
+        .count()
+}
+
+fn play_game(program: &mut [isize]) -> isize {
+    // Set address 0 to 2 to "play for free"
+    program[0] = 2;
+
+    let mut game = BrickBreaker::new(program);
+
+    game.run();
+    game.score
+}
+
+struct BrickBreaker {
+    intcode: IntCode,
+    screen: SparseGrid<isize>,
+    paddle: isize,
+    ball: (isize, isize),
+    score: isize,
+}
+
+impl BrickBreaker {
+    fn new(program: &[isize]) -> Self {
+        Self {
+            intcode: IntCode::new(program),
+            screen: SparseGrid::new(),
+            paddle: 0,
+            ball: (0, 0),
+            score: 0,
+        }
+    }
+
+    fn run(&mut self) {
+        self.run_with(|_| ())
+    }
+
+    /// Plays the game, calling `on_step` every time the program waits for a joystick move.
+    fn run_with<F: FnMut(&Self)>(&mut self, mut on_step: F) {
+        let mut next_move_opt = None;
+        while !self.intcode.has_halted() {
+            let output = match next_move_opt {
+                Some(next_move) => self.intcode.run_till_input_needed(&[next_move]),
+                None => self.intcode.run_till_input_needed(&[]),
+            };
+            for tile in output.chunks(3) {
+                let x = tile[0];
+                let y = tile[1];
+                let tile_id = tile[2];
+
+                if x == -1 && y == 0 {
+                    self.score = tile_id;
+                    continue;
+                }
+                self.screen.insert(Point::new(x, y), tile_id);
+                match tile_id {
+                    0..=2 => (),
+                    3 => self.paddle = x,
+                    4 => self.ball = (x, y),
+                    _ => panic!("Unexpected tile id!"),
+                }
+            }
+            next_move_opt = Some((self.ball.0 - self.paddle).signum());
+            on_step(self);
+        }
+    }
+}
+
+impl Frame for BrickBreaker {
+    fn frame(&self) -> Grid<Rgb> {
+        let (screen, _) = self.screen.to_grid(0);
+        screen.map(|&tile| match tile {
+            1 => render::GREY,
+            2 => render::BLUE,
+            3 => render::WHITE,
+            4 => render::RED,
+            _ => render::BLACK,
+        })
+    }
+}
@@ -1,20 +1,33 @@
-use advent2019::{get_raw_input, AdventError, AdventResult};
+use crate::solution::Solution;
+use crate::{AdventError, AdventResult};
 use regex::Regex;
 use std::cmp::Ordering;
 use std::collections::HashMap;
 
-fn main() -> AdventResult<()> {
-    let input = get_raw_input(14)?;
-    let reactions = &format_input(input)?;
+pub struct Day14;
 
-    solve_part1(reactions);
-    solve_part2(reactions);
-    Ok(())
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    type Input = Reactions;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> AdventResult<Reactions> {
+        format_input(input.to_string())
+    }
+
+    fn part1(reactions: &Reactions) -> AdventResult<usize> {
+        Ok(solve_part1(reactions))
+    }
+
+    fn part2(reactions: &Reactions) -> AdventResult<usize> {
+        Ok(solve_part2(reactions))
+    }
 }
 
-type Reactions = HashMap<String, Reaction>;
+pub type Reactions = HashMap<String, Reaction>;
 
-struct Reaction {
+pub struct Reaction {
     input: Vec<Chemical>,
     output: Chemical,
 }
@@ -26,8 +39,7 @@ struct Chemical {
 }
 
 fn format_input(input: String) -> AdventResult<Reactions> {
-    let re = Regex::new(r"(?P<quantity>\d+) (?P<chemical>[A-Z]+)")
-        .map_err(|_| AdventError::InvalidValue)?;
+    let re = Regex::new(r"(?P<quantity>\d+) (?P<chemical>[A-Z]+)")?;
     let mut reactions = Vec::with_capacity(input.lines().count());
     for formula_str in input.lines() {
         let mut chemicals: Vec<Chemical> = Vec::new();
@@ -39,7 +51,7 @@ fn format_input(input: String) -> AdventResult<Reactions> {
                 quantity,
             })
         }
-        let output = chemicals.pop().unwrap();
+        let output = chemicals.pop().ok_or(AdventError::InvalidValue)?;
         let reaction = Reaction {
             input: chemicals,
             output,
@@ -53,16 +65,12 @@ fn format_input(input: String) -> AdventResult<Reactions> {
 }
 
 fn solve_part1(reactions: &Reactions) -> usize {
-    let res = ore_needed_for_n_fuel(1, reactions);
-    println!("Ore needed: {}", res);
-    res
+    ore_needed_for_n_fuel(1, reactions)
 }
 
 fn solve_part2(reactions: &Reactions) -> usize {
     let trillion = 1_000_000_000_000;
-    let res = ore_to_fuel(trillion, reactions);
-    println!("Fuel from a trillion ore: {}", res);
-    res
+    ore_to_fuel(trillion, reactions)
 }
 
 fn ore_needed_for_n_fuel(n: usize, reactions: &Reactions) -> usize {
@@ -121,9 +129,8 @@ fn ore_to_fuel(ore_quantity: usize, reactions: &Reactions) -> usize {
         upper_fuel += lower_fuel
     }
 
-    println!("Upperbound: x{}", upper_fuel / lower_fuel);
     while lower_fuel < upper_fuel {
-        let mid = (lower_fuel + upper_fuel + 1) / 2;
+        let mid = (lower_fuel + upper_fuel).div_ceil(2);
         match ore_needed_for_n_fuel(mid, reactions).cmp(&ore_quantity) {
             Ordering::Less => lower_fuel = mid,
             Ordering::Equal => return mid,
@@ -0,0 +1,150 @@
+use crate::geometry::{Direction, Point};
+use crate::grid::{Grid, SparseGrid};
+use crate::intcode::IntCode;
+use crate::render::{self, Frame, Recorder, Rgb};
+use crate::search;
+use crate::solution::Solution;
+use crate::{parse_input, AdventError, AdventResult};
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    type Input = Vec<isize>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> AdventResult<Vec<isize>> {
+        parse_input::<isize>(input)?.first_row()
+    }
+
+    fn part1(program: &Vec<isize>) -> AdventResult<usize> {
+        solve_part1(program)
+    }
+
+    fn part2(program: &Vec<isize>) -> AdventResult<usize> {
+        solve_part2(program)
+    }
+
+    fn record(program: &Vec<isize>, recorder: &mut Recorder) -> AdventResult<()> {
+        let mut oxygen = OxygenSpread::new(program);
+        recorder.capture(&oxygen);
+        while oxygen.step() {
+            recorder.capture(&oxygen);
+        }
+        Ok(())
+    }
+}
+
+fn solve_part1(input: &[isize]) -> AdventResult<usize> {
+    let map = explore_area(input);
+    let oxygen = map.find(&Tile::Oxygen).ok_or(AdventError::InvalidValue)?;
+    let path = search::bfs(
+        &Point::default(),
+        |&p| open_neighbours(&map, p),
+        |&p| p == oxygen,
+    )
+    .ok_or(AdventError::InvalidValue)?;
+    Ok(path.len() - 1)
+}
+
+fn solve_part2(input: &[isize]) -> AdventResult<usize> {
+    let map = explore_area(input);
+    let oxygen = map.find(&Tile::Oxygen).ok_or(AdventError::InvalidValue)?;
+    let distances = search::flood_fill(&oxygen, |&p| open_neighbours(&map, p));
+    distances
+        .values()
+        .max()
+        .copied()
+        .ok_or(AdventError::InvalidValue)
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Tile {
+    Wall,
+    Open,
+    Oxygen,
+}
+
+fn movement_command(dir: Direction) -> isize {
+    match dir {
+        Direction::Up => 1,
+        Direction::Down => 2,
+        Direction::Left => 3,
+        Direction::Right => 4,
+    }
+}
+
+fn explore_area(program: &[isize]) -> SparseGrid<Tile> {
+    let mut map = SparseGrid::new();
+    map.insert(Point::default(), Tile::Open);
+    explore(IntCode::new(program), Point::default(), &mut map);
+    map
+}
+
+/// Maps the whole area by sending a copy of the droid into every unknown neighbour.
+fn explore(intcode: IntCode, position: Point<isize>, map: &mut SparseGrid<Tile>) {
+    for &dir in Direction::ALL.iter() {
+        let next = position + dir.offset();
+        if map.get(next).is_some() {
+            continue;
+        }
+        let mut droid = intcode.clone();
+        let tile = match droid.run_till_output(&[movement_command(dir)]) {
+            0 => Tile::Wall,
+            1 => Tile::Open,
+            2 => Tile::Oxygen,
+            _ => panic!("Unexpected status!"),
+        };
+        map.insert(next, tile);
+        if tile != Tile::Wall {
+            explore(droid, next, map);
+        }
+    }
+}
+
+fn open_neighbours(map: &SparseGrid<Tile>, p: Point<isize>) -> Vec<Point<isize>> {
+    map.neighbours4(p)
+        .filter(|(_, &tile)| tile != Tile::Wall)
+        .map(|(n, _)| n)
+        .collect()
+}
+
+struct OxygenSpread {
+    map: SparseGrid<Tile>,
+}
+
+impl OxygenSpread {
+    fn new(program: &[isize]) -> Self {
+        Self {
+            map: explore_area(program),
+        }
+    }
+
+    /// Fills every open tile next to oxygen, false once the area is full.
+    fn step(&mut self) -> bool {
+        let front: Vec<_> = self
+            .map
+            .iter()
+            .filter(|&(p, tile)| {
+                *tile == Tile::Open && self.map.neighbours4(p).any(|(_, t)| *t == Tile::Oxygen)
+            })
+            .map(|(p, _)| p)
+            .collect();
+        for &p in front.iter() {
+            self.map.insert(p, Tile::Oxygen);
+        }
+        !front.is_empty()
+    }
+}
+
+impl Frame for OxygenSpread {
+    fn frame(&self) -> Grid<Rgb> {
+        let (map, _) = self.map.to_grid(Tile::Wall);
+        map.map(|tile| match tile {
+            Tile::Wall => render::GREY,
+            Tile::Open => render::BLACK,
+            Tile::Oxygen => render::BLUE,
+        })
+    }
+}
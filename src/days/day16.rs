@@ -1,25 +1,30 @@
-use advent2019::{get_input, AdventResult};
+use crate::solution::Solution;
+use crate::{parse_input, AdventResult};
 
 const PATTERN: &[isize] = &[0, 1, 0, -1];
 
-fn main() -> AdventResult<()> {
-    let input = get_input::<String>(16)?.first_element();
-    let input = &string_to_vec(input);
-    solve_part1(input)?;
-    solve_part2(input)?;
-    Ok(())
-}
+pub struct Day16;
 
-fn solve_part1(input: &[isize]) -> AdventResult<()> {
-    let res = fft(input, 100);
-    println!("FFT output: {}", vec_to_string(&res[..8]));
-    Ok(())
-}
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    type Input = Vec<isize>;
+    type Part1 = String;
+    type Part2 = String;
+
+    fn parse(input: &str) -> AdventResult<Vec<isize>> {
+        let input = parse_input::<String>(input)?.first_element()?;
+        Ok(string_to_vec(input))
+    }
 
-fn solve_part2(input: &[isize]) -> AdventResult<()> {
-    let res = fft_real(input, 100);
-    println!("FFT real signal output: {}", vec_to_string(&res[..8]));
-    Ok(())
+    fn part1(input: &Vec<isize>) -> AdventResult<String> {
+        let res = fft(input, 100);
+        Ok(vec_to_string(&res[..8]))
+    }
+
+    fn part2(input: &Vec<isize>) -> AdventResult<String> {
+        let res = fft_real(input, 100);
+        Ok(vec_to_string(&res[..8]))
+    }
 }
 
 fn string_to_vec<T: AsRef<str>>(input: T) -> Vec<isize> {
@@ -123,18 +128,18 @@ fn fft_real_partial_sum(input: &[isize], phases: usize, offset: usize) -> Vec<is
         partial_sums.push(0);
         let mut prev = 0;
         for r in res.iter() {
-            partial_sums.push(*r  + prev);
+            partial_sums.push(*r + prev);
             prev += *r;
         }
 
         let len = res.len();
         for (i, r) in res.iter_mut().enumerate() {
             let mut output = 0;
-            for idx in (i..=len).step_by(4 * (offset+i)) {
+            for idx in (i..=len).step_by(4 * (offset + i)) {
                 output += partial_sums[(offset + idx).min(len)] - partial_sums[idx];
             }
 
-            for idx in (3*(offset+i)..=len).step_by(4 * (offset+i)) {
+            for idx in (3 * (offset + i)..=len).step_by(4 * (offset + i)) {
                 output -= partial_sums[(offset + idx).min(len)] - partial_sums[idx];
             }
             *r = output.abs() % 10;
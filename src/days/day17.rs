@@ -0,0 +1,212 @@
+use crate::geometry::{Direction, Point, Turn};
+use crate::grid::Grid;
+use crate::intcode;
+use crate::solution::{Auxiliary, Solution};
+use crate::tui::{AsciiTerminal, Session};
+use crate::{parse_input, AdventError, AdventResult};
+use itertools::Itertools;
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    type Input = Vec<isize>;
+    type Part1 = usize;
+    type Part2 = isize;
+
+    fn parse(input: &str) -> AdventResult<Vec<isize>> {
+        parse_input::<isize>(input)?.first_row()
+    }
+
+    fn part1(program: &Vec<isize>) -> AdventResult<usize> {
+        solve_part1(program)
+    }
+
+    fn part2(program: &Vec<isize>) -> AdventResult<isize> {
+        solve_part2(program)
+    }
+
+    fn auxiliary(program: &Vec<isize>) -> AdventResult<Auxiliary> {
+        let (main, a, b, c) = movement_routines(program)?;
+        let mut auxiliary = Auxiliary::new();
+        auxiliary.insert("main".to_string(), main.into());
+        auxiliary.insert("a".to_string(), a.into());
+        auxiliary.insert("b".to_string(), b.into());
+        auxiliary.insert("c".to_string(), c.into());
+        Ok(auxiliary)
+    }
+
+    // Wakes the robot up so it asks for movement routines
+    fn console(program: &Vec<isize>) -> Option<Session> {
+        let mut program = program.to_vec();
+        program[0] = 2;
+        Some(Session::new(&program, AsciiTerminal::new()))
+    }
+}
+
+fn solve_part1(input: &[isize]) -> AdventResult<usize> {
+    let grid = &parse_grid(input)?;
+    Ok(count_scaffold_intersections(grid))
+}
+
+fn movement_routines(input: &[isize]) -> AdventResult<(String, String, String, String)> {
+    let grid = &parse_grid(input)?;
+    let mut s = Scaffold::new(grid)?;
+    s.derive_path();
+    brute_force(&s.cmd).ok_or(AdventError::InvalidValue)
+}
+
+fn solve_part2(input: &[isize]) -> AdventResult<isize> {
+    let (main, a, b, c) = movement_routines(input)?;
+
+    let continuous_video_feed = "n\n".to_string();
+
+    let program_input: Vec<_> = [main, a, b, c, continuous_video_feed]
+        .join("\n")
+        .chars()
+        .map(|c| c as u8)
+        .map(|c| c as isize)
+        .collect();
+
+    let mut input_override = input.to_vec();
+    input_override[0] = 2;
+
+    let output = intcode::run_program(&input_override, &program_input);
+    output.last().copied().ok_or(AdventError::InvalidValue)
+}
+
+fn parse_grid(input: &[isize]) -> AdventResult<Grid<char>> {
+    let output: Vec<isize> = intcode::run_program(input, &[]);
+    let output: String = output.iter().map(|d| (*d as u8) as char).collect();
+    output.parse()
+}
+
+fn count_scaffold_intersections(grid: &Grid<char>) -> usize {
+    grid.points()
+        .filter(|&p| grid[p] == '#')
+        .filter(|&p| grid.neighbours4(p).filter(|&n| grid[n] == '#').count() == 4)
+        .map(|p| p.x * p.y)
+        .sum()
+}
+
+struct Scaffold<'a> {
+    grid: &'a Grid<char>,
+    pos: Point<usize>,
+    dir: Direction,
+    cmd: Vec<String>,
+}
+
+impl<'a> Scaffold<'a> {
+    fn new(grid: &'a Grid<char>) -> AdventResult<Self> {
+        let start = grid.find(&'^').ok_or(AdventError::InvalidValue)?;
+        Ok(Self {
+            grid,
+            pos: start,
+            dir: Direction::Up,
+            cmd: Vec::new(),
+        })
+    }
+
+    fn next_position(&self, dir: Direction) -> Option<Point<usize>> {
+        self.pos
+            .step(dir)
+            .filter(|&p| self.grid.get(p) == Some(&'#'))
+    }
+
+    fn next_turn(&self) -> Option<Turn> {
+        [Turn::Left, Turn::Right]
+            .iter()
+            .copied()
+            .find(|&turn| self.next_position(self.dir.turn(turn)).is_some())
+    }
+
+    fn derive_path(&mut self) {
+        while let Some(turn) = self.next_turn() {
+            self.cmd.push(turn.to_char().to_string());
+            self.dir = self.dir.turn(turn);
+            let mut steps = 0u8;
+            while let Some(pos) = self.next_position(self.dir) {
+                steps += 1;
+                self.pos = pos;
+            }
+            self.cmd.push(steps.to_string())
+        }
+    }
+}
+
+fn replace_slice<T>(buf: &mut Vec<T>, from: &[T], to: &[T])
+where
+    T: Clone + PartialEq,
+{
+    let mut i = 0;
+    while i <= buf.len() - from.len() {
+        if buf[i..].starts_with(from) {
+            buf.splice(i..i + from.len(), to.iter().cloned());
+            i += to.len()
+        } else {
+            i += 1
+        }
+    }
+}
+
+fn brute_force(input: &[String]) -> Option<(String, String, String, String)> {
+    let funcs = ["A".to_string(), "B".to_string(), "C".to_string()];
+    for a_len in (2..20).step_by(2) {
+        let a = &input[..a_len];
+        let mut input_a = input.to_vec();
+        replace_slice(&mut input_a, a, &funcs[0..1]);
+        for b_len in (2..20).step_by(2) {
+            let mut input_b = input_a.to_vec();
+            let b: Vec<String> = input_b
+                .iter()
+                .skip_while(|v| funcs.contains(v))
+                .take_while(|v| !funcs.contains(v))
+                .take(b_len)
+                .cloned()
+                .collect();
+            if b.len() < b_len {
+                break;
+            }
+            replace_slice(&mut input_b, &b, &funcs[1..2]);
+
+            let c: Vec<String> = input_b
+                .iter()
+                .skip_while(|v| funcs.contains(v))
+                .take_while(|v| !funcs.contains(v))
+                .cloned()
+                .collect();
+
+            replace_slice(&mut input_b, &c, &funcs[2..3]);
+            let left = input_b.iter().filter(|v| !funcs.contains(v)).count();
+            if left == 0 {
+                return Some((input_b.join(","), a.join(","), b.join(","), c.join(",")));
+            }
+        }
+    }
+    None
+}
+
+#[allow(dead_code)]
+fn pair_encoding(input: &[String], limit: usize) -> Vec<String> {
+    let mut s = input.to_vec();
+    let mut idx = 0;
+    while s.len() > limit {
+        idx += 1;
+
+        let mut pairs: Vec<_> = s.chunks(2).collect();
+        pairs.sort();
+        let most_frequent_pair = pairs
+            .iter()
+            .group_by(|v| *v)
+            .into_iter()
+            .map(|(a, b)| (b.count(), *a))
+            .max()
+            .unwrap()
+            .1;
+
+        let mut pair = [String::new(), String::new()];
+        pair.clone_from_slice(most_frequent_pair);
+        replace_slice(&mut s, &pair, &[idx.to_string()]);
+    }
+    s
+}
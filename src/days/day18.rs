@@ -0,0 +1,293 @@
+use crate::geometry::Point;
+use crate::grid::Grid;
+use crate::search;
+use crate::solution::Solution;
+use crate::{AdventError, AdventResult};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+    type Input = Grid<char>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> AdventResult<Grid<char>> {
+        input.parse()
+    }
+
+    fn part1(maze: &Grid<char>) -> AdventResult<u32> {
+        solve_part1(maze)
+    }
+
+    fn part2(maze: &Grid<char>) -> AdventResult<u32> {
+        solve_part2(maze)
+    }
+}
+
+fn solve_part1(input: &Grid<char>) -> AdventResult<u32> {
+    let maze = Maze::new(input)?;
+    maze.shortest_path_to_all_keys()
+        .ok_or(AdventError::InvalidValue)
+}
+
+fn solve_part2(input: &Grid<char>) -> AdventResult<u32> {
+    let grid = &mut input.clone();
+    let maze = Maze::new_part2(grid)?;
+    maze.shortest_path_to_all_keys_part2()
+        .ok_or(AdventError::InvalidValue)
+}
+
+#[derive(Clone)]
+struct Maze<'a> {
+    grid: &'a Grid<char>,
+    start: Node,
+    key_count: u32,
+}
+
+impl<'a> Maze<'a> {
+    fn new(grid: &'a Grid<char>) -> AdventResult<Self> {
+        let start = grid.find(&'@').ok_or(AdventError::InvalidValue)?;
+        let key_count = grid.values().filter(|c| c.is_ascii_lowercase()).count() as u32;
+        Ok(Self {
+            grid,
+            start,
+            key_count,
+        })
+    }
+
+    fn new_part2(grid: &'a mut Grid<char>) -> AdventResult<Self> {
+        let start = grid.find(&'@').ok_or(AdventError::InvalidValue)?;
+        let key_count = grid.values().filter(|c| c.is_ascii_lowercase()).count() as u32;
+
+        let walls: Vec<_> = grid.neighbours4(start).collect();
+        grid[start] = '#';
+        for wall in walls {
+            grid[wall] = '#';
+        }
+
+        Ok(Self {
+            grid,
+            start,
+            key_count,
+        })
+    }
+
+    fn value(&self, pos: Node) -> char {
+        self.grid[pos]
+    }
+
+    fn key_to_bitmask(c: char) -> u32 {
+        if c.is_ascii_alphabetic() {
+            1 << (c.to_ascii_lowercase() as u8 - b'a')
+        } else {
+            0
+        }
+    }
+
+    fn reachable_keys(
+        &self,
+        start_node: Node,
+        adjacency_map: &mut HashMap<Node, Vec<(Node, u32, u32)>>,
+    ) {
+        let reachable_keys = adjacency_map.entry(start_node).or_default();
+
+        let mut visited = HashSet::new();
+        let mut queue = VecDeque::new();
+        let start_val = self.value(start_node);
+        queue.push_front((start_node, 0u32, 0u32));
+
+        while let Some((node, required_keys, distance)) = queue.pop_front() {
+            let val = self.value(node);
+            if val.is_ascii_lowercase() && val != start_val && val != '@' {
+                reachable_keys.push((node, required_keys, distance));
+                continue;
+            }
+
+            let neighbours = self
+                .grid
+                .neighbours4(node)
+                .filter(|node| self.value(*node) != '#');
+
+            for neighbour in neighbours {
+                if visited.insert(neighbour) {
+                    let val = self.value(neighbour);
+                    let new_key_required = if val.is_ascii_uppercase() {
+                        Self::key_to_bitmask(val)
+                    } else {
+                        0
+                    };
+                    queue.push_back((neighbour, required_keys | new_key_required, distance + 1))
+                }
+            }
+        }
+    }
+
+    fn shortest_path_to_all_keys(&self) -> Option<u32> {
+        let adjacency_map = &mut HashMap::new();
+
+        search::dijkstra(
+            &(self.start, 0u32),
+            |&(node, keys): &(Node, u32)| {
+                if !adjacency_map.contains_key(&node) {
+                    self.reachable_keys(node, adjacency_map);
+                }
+                adjacency_map[&node]
+                    .clone()
+                    .into_iter()
+                    .filter(move |(_, required_keys, _)| keys & required_keys == *required_keys)
+                    .map(move |(node, _, distance)| {
+                        (
+                            (node, keys | Self::key_to_bitmask(self.value(node))),
+                            distance,
+                        )
+                    })
+            },
+            |&(_, keys)| keys.count_ones() == self.key_count,
+        )
+        .map(|(_, shortest_path)| shortest_path)
+    }
+
+    fn shortest_path_to_all_keys_part2(&self) -> Option<u32> {
+        let adjacency_map = &mut HashMap::new();
+
+        let Point { x, y } = self.start;
+
+        let robots = [
+            Point::new(x - 1, y - 1),
+            Point::new(x + 1, y - 1),
+            Point::new(x - 1, y + 1),
+            Point::new(x + 1, y + 1),
+        ];
+
+        search::dijkstra(
+            &(robots, 0u32),
+            |&(robots, keys)| {
+                let mut successors = Vec::with_capacity(8);
+
+                for (robot_id, robot) in robots.iter().enumerate() {
+                    if !adjacency_map.contains_key(robot) {
+                        self.reachable_keys(*robot, adjacency_map);
+                    }
+
+                    let mut new_robots = robots;
+                    successors.extend(
+                        adjacency_map[robot]
+                            .clone()
+                            .into_iter()
+                            .filter(move |(_, required_keys, _)| {
+                                keys & required_keys == *required_keys
+                            })
+                            .map(move |(neighbor, _, distance)| {
+                                new_robots[robot_id] = neighbor;
+                                (
+                                    (
+                                        new_robots,
+                                        keys | Self::key_to_bitmask(self.value(neighbor)),
+                                    ),
+                                    distance,
+                                )
+                            }),
+                    );
+                }
+
+                successors
+            },
+            |&(_, keys)| keys.count_ones() == self.key_count,
+        )
+        .map(|(_, shortest_path)| shortest_path)
+    }
+}
+
+type Node = Point<usize>;
+
+#[cfg(test)]
+fn test_part1(input: &[&str], output: u32) {
+    let input: Grid<char> = input.join("\n").parse().unwrap();
+    let maze = Maze::new(&input).unwrap();
+    let res = maze.shortest_path_to_all_keys();
+    assert_eq!(res, Some(output));
+}
+
+#[cfg(test)]
+fn test_part2(input: &[&str], output: u32) {
+    let mut input: Grid<char> = input.join("\n").parse().unwrap();
+    let maze = Maze::new_part2(&mut input).unwrap();
+    let res = maze.shortest_path_to_all_keys_part2();
+    assert_eq!(res, Some(output));
+}
+
+#[test]
+fn test_day18_case1() {
+    let input = &[
+        "#################",
+        "#i.G..c...e..H.p#",
+        "########.########",
+        "#j.A..b...f..D.o#",
+        "########@########",
+        "#k.E..a...g..B.n#",
+        "########.########",
+        "#l.F..d...h..C.m#",
+        "#################",
+    ];
+    test_part1(input, 136)
+}
+
+#[test]
+fn test_day18_case1_part2() {
+    let input = &[
+        "#######", //
+        "#a.#Cd#", //
+        "##...##", //
+        "##.@.##", //
+        "##...##", //
+        "#cB#Ab#", //
+        "#######", //
+    ];
+    test_part2(input, 8)
+}
+
+#[test]
+fn test_day18_case2_part2() {
+    let input = &[
+        "###############",
+        "#d.ABC.#.....a#",
+        "######...######",
+        "######.@.######",
+        "######...######",
+        "#b.....#.....c#",
+        "###############",
+    ];
+    test_part2(input, 24)
+}
+
+#[test]
+fn test_day18_case3_part2() {
+    let input = &[
+        "#############",
+        "#DcBa.#.GhKl#",
+        "#.###...#I###",
+        "#e#d#.@.#j#k#",
+        "###C#...###J#",
+        "#fEbA.#.FgHi#",
+        "#############",
+    ];
+    test_part2(input, 32)
+}
+
+#[test]
+fn test_day18_case4_part2() {
+    let input = &[
+        "#############",
+        "#g#f.D#..h#l#",
+        "#F###e#E###.#",
+        "#dCba...BcIJ#",
+        "#####.@.#####",
+        "#nK.L...G...#",
+        "#M###N#H###.#",
+        "#o#m..#i#jk.#",
+        "#############",
+    ];
+    test_part2(input, 72)
+}
@@ -1,41 +1,42 @@
-use advent2019::intcode::get_program_last_output;
-use advent2019::{get_input, AdventResult};
+use crate::intcode::get_program_last_output;
+use crate::parallel::Pool;
+use crate::solution::Solution;
+use crate::{parse_input, AdventResult};
 use std::collections::HashMap;
 use SearchResult::*;
 
-fn main() -> AdventResult<()> {
-    let program = &get_input::<isize>(19)?.first_row();
-    solve_part1(program)?;
-    solve_part2(program)?;
-    Ok(())
-}
+pub struct Day19;
 
-fn solve_part1(input: &[isize]) -> AdventResult<()> {
-    let mut res = HashMap::new();
-    for i in 0..50 {
-        for j in 0..50 {
-            let pull = get_program_last_output(input, &[i, j]);
-            res.insert((i, j), pull);
-            // Prints the points
-            // if pull > 0 {
-            // println!("{}\t{}\t{}", i, j, pull);
-            // }
-        }
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+    type Input = Vec<isize>;
+    type Part1 = usize;
+    type Part2 = isize;
+
+    fn parse(input: &str) -> AdventResult<Vec<isize>> {
+        parse_input::<isize>(input)?.first_row()
     }
 
-    let points_affected = res.iter().filter(|(_, pull)| **pull > 0).count();
-    println!("Points affected: {}", points_affected);
+    fn part1(program: &Vec<isize>) -> AdventResult<usize> {
+        solve_part1(program)
+    }
+
+    fn part2(program: &Vec<isize>) -> AdventResult<isize> {
+        solve_part2(program)
+    }
+}
 
-    Ok(())
+fn solve_part1(input: &[isize]) -> AdventResult<usize> {
+    let points: Vec<[isize; 2]> = (0..50).flat_map(|i| (0..50).map(move |j| [i, j])).collect();
+    let pulls = Pool::default().map(&points, |point| get_program_last_output(input, point));
+    Ok(pulls.iter().filter(|&&pull| pull > 0).count())
 }
 
-fn solve_part2(input: &[isize]) -> AdventResult<()> {
+fn solve_part2(input: &[isize]) -> AdventResult<isize> {
     let bs = &mut BeamSearch::new(input);
 
     let (x, y) = bs.search_square(100);
-    println!("Best: ({} {}) => {:?}", x, y, bs.search(x, y));
-    println!("Result: {:?}", (x - 99) * 10000 + y);
-    Ok(())
+    Ok((x - 99) * 10000 + y)
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -1,42 +1,48 @@
-use advent2019::{get_input, AdventResult};
-use pathfinding::directed::bfs;
+use crate::geometry::Point;
+use crate::grid::Grid;
+use crate::search;
+use crate::solution::Solution;
+use crate::{AdventError, AdventResult};
 use std::collections::HashMap;
 
-fn main() -> AdventResult<()> {
-    let input = &get_input::<String>(20)?.first_column();
-    let grid = &input
-        .iter()
-        .map(|row| row.chars().collect::<Vec<_>>())
-        .collect::<Vec<_>>();
-    let maze = &Maze::new(grid);
-    solve_part1(maze)?;
-    solve_part2(maze)?;
-    Ok(())
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+    type Input = Grid<char>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> AdventResult<Grid<char>> {
+        input.parse()
+    }
+
+    fn part1(grid: &Grid<char>) -> AdventResult<usize> {
+        solve_part1(&Maze::new(grid))
+    }
+
+    fn part2(grid: &Grid<char>) -> AdventResult<usize> {
+        solve_part2(&Maze::new(grid))
+    }
 }
 
-fn solve_part1(maze: &Maze) -> AdventResult<()> {
-    let res = maze.shortest_path(false).unwrap();
-    println!("Shortest path: {}", res);
-    Ok(())
+fn solve_part1(maze: &Maze) -> AdventResult<usize> {
+    maze.shortest_path(false).ok_or(AdventError::InvalidValue)
 }
 
-#[allow(dead_code)]
-fn solve_part2(maze: &Maze) -> AdventResult<()> {
-    // let maze = Maze::new(input);
-    let res = maze.shortest_path(true).unwrap();
-    println!("Shortest path with levels: {}", res);
-    Ok(())
+fn solve_part2(maze: &Maze) -> AdventResult<usize> {
+    maze.shortest_path(true).ok_or(AdventError::InvalidValue)
 }
 
 #[derive(Debug, Hash, PartialEq, Eq, Clone)]
 struct Portal {
-    pos: (usize, usize),
+    pos: Point<usize>,
     name: String,
     outer: bool,
 }
 
 impl Portal {
-    fn new<T: AsRef<str>>(name: T, pos: (usize, usize), outer: bool) -> Self {
+    fn new<T: AsRef<str>>(name: T, pos: Point<usize>, outer: bool) -> Self {
         Portal {
             pos,
             name: name.as_ref().to_string(),
@@ -47,7 +53,7 @@ impl Portal {
 
 #[derive(Debug, Hash, PartialEq, Eq, Clone)]
 struct Loc {
-    pos: (usize, usize),
+    pos: Point<usize>,
     level: usize,
 }
 
@@ -62,22 +68,22 @@ impl Loc {
 
 #[derive(Clone)]
 struct Maze<'a> {
-    grid: &'a [Vec<char>],
+    grid: &'a Grid<char>,
     start: Loc,
     end: Loc,
-    portals: HashMap<(usize, usize), Portal>,
+    portals: HashMap<Point<usize>, Portal>,
 }
 
 impl<'a> Maze<'a> {
-    fn new(grid: &'a [Vec<char>]) -> Self {
+    fn new(grid: &'a Grid<char>) -> Self {
         let mut portals = Self::portals(grid);
 
         let start = Loc::from_portal(&portals.remove("AA").expect("start")[0]);
         let end = Loc::from_portal(&portals.remove("ZZ").expect("end")[0]);
 
         let portals: HashMap<_, _> = portals
-            .into_iter()
-            .flat_map(|(_, mut doors)| {
+            .into_values()
+            .flat_map(|mut doors| {
                 let p0 = doors.remove(0);
                 let p1 = doors.remove(0);
                 let p0_pos = p0.pos;
@@ -94,38 +100,40 @@ impl<'a> Maze<'a> {
         }
     }
 
-    fn portals(grid: &[Vec<char>]) -> HashMap<String, Vec<Portal>> {
+    fn portals(grid: &Grid<char>) -> HashMap<String, Vec<Portal>> {
+        let (rows, cols) = (grid.height(), grid.width());
+        let at = |x: usize, y: usize| grid[Point::new(x, y)];
         let mut portals: HashMap<String, Vec<Portal>> = HashMap::new();
-        for (i, row) in grid.iter().enumerate().skip(1).take(grid.len() - 2) {
-            for (j, c) in row.iter().enumerate().skip(1).take(grid[0].len() - 2) {
+        for i in 1..rows - 1 {
+            for j in 1..cols - 1 {
+                let c = at(j, i);
                 if c.is_ascii_uppercase() {
-                    let is_outer =
-                        i == 1 || i == grid.len() - 2 || j == 1 || j == grid[0].len() - 2;
+                    let is_outer = i == 1 || i == rows - 2 || j == 1 || j == cols - 2;
                     // Vertical portal - point down
-                    if grid[i - 1][j].is_ascii_uppercase() && grid[i + 1][j] == '.' {
-                        let name = format!("{}{}", grid[i - 1][j], c);
-                        let portal = Portal::new(name.clone(), (i + 1, j), is_outer);
+                    if at(j, i - 1).is_ascii_uppercase() && at(j, i + 1) == '.' {
+                        let name = format!("{}{}", at(j, i - 1), c);
+                        let portal = Portal::new(name.clone(), Point::new(j, i + 1), is_outer);
                         portals.entry(name).or_default().push(portal);
                         continue;
                     }
                     // Vertical portal - point up
-                    if grid[i - 1][j] == '.' && grid[i + 1][j].is_ascii_uppercase() {
-                        let name = format!("{}{}", c, grid[i + 1][j]);
-                        let portal = Portal::new(name.clone(), (i - 1, j), is_outer);
+                    if at(j, i - 1) == '.' && at(j, i + 1).is_ascii_uppercase() {
+                        let name = format!("{}{}", c, at(j, i + 1));
+                        let portal = Portal::new(name.clone(), Point::new(j, i - 1), is_outer);
                         portals.entry(name).or_default().push(portal);
                         continue;
                     }
                     // Horizontal portal - point left
-                    if grid[i][j - 1] == '.' && grid[i][j + 1].is_ascii_uppercase() {
-                        let name = format!("{}{}", c, grid[i][j + 1]);
-                        let portal = Portal::new(name.clone(), (i, j - 1), is_outer);
+                    if at(j - 1, i) == '.' && at(j + 1, i).is_ascii_uppercase() {
+                        let name = format!("{}{}", c, at(j + 1, i));
+                        let portal = Portal::new(name.clone(), Point::new(j - 1, i), is_outer);
                         portals.entry(name).or_default().push(portal);
                         continue;
                     }
                     // Horizontal portal - point right
-                    if grid[i][j - 1].is_ascii_uppercase() && grid[i][j + 1] == '.' {
-                        let name = format!("{}{}", grid[i][j - 1], c);
-                        let portal = Portal::new(name.clone(), (i, j + 1), is_outer);
+                    if at(j - 1, i).is_ascii_uppercase() && at(j + 1, i) == '.' {
+                        let name = format!("{}{}", at(j - 1, i), c);
+                        let portal = Portal::new(name.clone(), Point::new(j + 1, i), is_outer);
                         portals.entry(name).or_default().push(portal);
                         continue;
                     }
@@ -136,7 +144,7 @@ impl<'a> Maze<'a> {
     }
 
     fn value(&self, loc: &Loc) -> char {
-        self.grid[loc.pos.0][loc.pos.1]
+        self.grid[loc.pos]
     }
 
     fn check_position(&self, loc: &Loc, with_levels: bool) -> bool {
@@ -152,36 +160,17 @@ impl<'a> Maze<'a> {
     }
 
     fn neighbours(&self, loc: &Loc, with_levels: bool) -> Vec<Loc> {
-        let mut neighbours = Vec::with_capacity(5);
-
         let pos = loc.pos;
-        if pos.0 > 2 {
-            neighbours.push(Loc {
-                pos: (pos.0 - 1, pos.1),
-                level: loc.level,
-            });
-        }
-
-        if pos.1 > 2 {
-            neighbours.push(Loc {
-                pos: (pos.0, pos.1 - 1),
+        // Rows and columns 0 and 1 on each side hold the portal labels
+        let (rows, cols) = (self.grid.height(), self.grid.width());
+        let mut neighbours: Vec<_> = pos
+            .neighbours4()
+            .filter(|p| 2 <= p.x && p.x < cols - 2 && 2 <= p.y && p.y < rows - 2)
+            .map(|pos| Loc {
+                pos,
                 level: loc.level,
-            });
-        }
-
-        if pos.0 < self.grid.len() - 3 {
-            neighbours.push(Loc {
-                pos: (pos.0 + 1, pos.1),
-                level: loc.level,
-            });
-        }
-
-        if pos.1 < self.grid[0].len() - 3 {
-            neighbours.push(Loc {
-                pos: (pos.0, pos.1 + 1),
-                level: loc.level,
-            });
-        }
+            })
+            .collect();
 
         if let Some(dst_portal) = self.portals.get(&pos) {
             let mut level = loc.level;
@@ -210,7 +199,7 @@ impl<'a> Maze<'a> {
 
     fn shortest_path(&self, with_levels: bool) -> Option<usize> {
         let start = &self.start;
-        let shortest_path_opt = bfs::bfs(
+        let shortest_path_opt = search::bfs(
             start,
             |pos| self.neighbours(pos, with_levels),
             |pos| *pos == self.end,
@@ -260,10 +249,7 @@ fn test_day20_case1() {
         "           B   J   C               ",
         "           U   P   P               ",
     ];
-    let input: Vec<_> = input
-        .iter()
-        .map(|s| s.chars().collect::<Vec<_>>())
-        .collect();
+    let input: Grid<char> = input.join("\n").parse().unwrap();
     let maze = Maze::new(&input);
     let res = maze.shortest_path(false);
 
@@ -311,10 +297,7 @@ fn test_day20_case1_part2() {
         "               A O F   N                     ",
         "               A A D   M                     ",
     ];
-    let input: Vec<_> = input
-        .iter()
-        .map(|s| s.chars().collect::<Vec<_>>())
-        .collect();
+    let input: Grid<char> = input.join("\n").parse().unwrap();
     let maze = Maze::new(&input);
     let res = maze.shortest_path(true);
 
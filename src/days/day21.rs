@@ -1,14 +1,34 @@
-use advent2019::intcode;
-use advent2019::{get_input, AdventResult};
+use crate::intcode;
+use crate::solution::Solution;
+use crate::tui::{AsciiTerminal, Session};
+use crate::{parse_input, AdventError, AdventResult};
 
-fn main() -> AdventResult<()> {
-    let program = &get_input::<isize>(21)?.first_row();
-    solve_part1(program);
-    solve_part2(program);
-    Ok(())
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+    type Input = Vec<isize>;
+    type Part1 = isize;
+    type Part2 = isize;
+
+    fn parse(input: &str) -> AdventResult<Vec<isize>> {
+        parse_input::<isize>(input)?.first_row()
+    }
+
+    fn part1(program: &Vec<isize>) -> AdventResult<isize> {
+        solve_part1(program)
+    }
+
+    fn part2(program: &Vec<isize>) -> AdventResult<isize> {
+        solve_part2(program)
+    }
+
+    fn console(program: &Vec<isize>) -> Option<Session> {
+        Some(Session::new(program, AsciiTerminal::new()))
+    }
 }
 
-fn solve_part1(input: &[isize]) {
+fn solve_part1(input: &[isize]) -> AdventResult<isize> {
     // NOTE: a jump is 4 tiles long
     let springdroid_code = &[
         // (No tile ahead = Jump)
@@ -28,7 +48,7 @@ fn solve_part1(input: &[isize]) {
     run_springdroid(input, springdroid_code)
 }
 
-fn solve_part2(input: &[isize]) {
+fn solve_part2(input: &[isize]) -> AdventResult<isize> {
     // NOTE: a jump is 4 tiles long
     let springdroid_code = &[
         // (No tile ahead = Jump)
@@ -53,7 +73,7 @@ fn solve_part2(input: &[isize]) {
     run_springdroid(input, springdroid_code)
 }
 
-fn run_springdroid(input: &[isize], springdroid_code: &[&str]) {
+fn run_springdroid(input: &[isize], springdroid_code: &[&str]) -> AdventResult<isize> {
     let springdroid_intcode_input: Vec<isize> = springdroid_code
         .join("\n")
         .chars()
@@ -62,14 +82,9 @@ fn run_springdroid(input: &[isize], springdroid_code: &[&str]) {
 
     let res = intcode::run_program(input, &springdroid_intcode_input);
 
-    println!(
-        "Springdroid output:\n{}",
-        res.iter()
-            .flat_map(|c| if 0 <= *c && *c < 256 {
-                vec![*c as u8 as char]
-            } else {
-                c.to_string().chars().collect::<Vec<_>>()
-            })
-            .collect::<String>()
-    );
+    // The hull damage is the only non-ASCII output, otherwise the droid fell into space
+    match res.last() {
+        Some(&damage) if damage >= 256 => Ok(damage),
+        _ => Err(AdventError::InvalidValue),
+    }
 }
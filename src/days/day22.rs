@@ -1,37 +1,109 @@
-use advent2019::{get_input, AdventError, AdventResult};
+#[cfg(test)]
+use crate::get_input;
+use crate::solution::{Auxiliary, Params, Solution};
+use crate::{parse_input, AdventError, AdventResult};
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+    type Input = Shuffling;
+    type Part1 = usize;
+    type Part2 = i128;
+
+    const PARAMS: &'static [&'static str] = &[
+        "deck_size",
+        "card",
+        "big_deck_size",
+        "shuffle_count",
+        "position",
+    ];
 
-fn main() -> AdventResult<()> {
-    let program = &get_input::<String>(22)?.first_column();
-    solve_part1(program)?;
-    solve_part2(program)?;
-    Ok(())
+    fn parse(input: &str) -> AdventResult<Shuffling> {
+        Self::parse_with(input, &Params::new())
+    }
+
+    fn parse_with(input: &str, params: &Params) -> AdventResult<Shuffling> {
+        let shuffles = parse_input::<String>(input)?
+            .first_column()?
+            .iter()
+            .map(parse_shuffle)
+            .collect::<AdventResult<_>>()?;
+        let shuffling = Shuffling {
+            shuffles,
+            deck_size: params.get("deck_size", 10007)?,
+            card: params.get("card", 2019)?,
+            big_deck_size: params.get("big_deck_size", 119_315_717_514_047)?,
+            shuffle_count: params.get("shuffle_count", 101_741_582_076_661)?,
+            position: params.get("position", 2020)?,
+        };
+        if shuffling.card >= shuffling.deck_size {
+            return Err(AdventError::InvalidParam("card".to_string()));
+        }
+        // Products of two cards must fit in an i128
+        let big_deck_size = shuffling.big_deck_size;
+        if big_deck_size > i128::from(i64::MAX) || !is_prime(big_deck_size) {
+            return Err(AdventError::InvalidParam("big_deck_size".to_string()));
+        }
+        if shuffling.position < 0 || shuffling.position >= shuffling.big_deck_size {
+            return Err(AdventError::InvalidParam("position".to_string()));
+        }
+        if shuffling.shuffle_count < 0 {
+            return Err(AdventError::InvalidParam("shuffle_count".to_string()));
+        }
+        Ok(shuffling)
+    }
+
+    fn part1(shuffling: &Shuffling) -> AdventResult<usize> {
+        solve_part1(shuffling)
+    }
+
+    fn part2(shuffling: &Shuffling) -> AdventResult<i128> {
+        solve_part2(shuffling)
+    }
+
+    fn auxiliary(shuffling: &Shuffling) -> AdventResult<Auxiliary> {
+        let deck_size = shuffling.big_deck_size;
+        let (a, b) = compound_shuffle(deck_size, &shuffling.shuffles);
+        let mut auxiliary = Auxiliary::new();
+        auxiliary.insert("deck_size".to_string(), (deck_size as u64).into());
+        auxiliary.insert("a".to_string(), (a as u64).into());
+        auxiliary.insert("b".to_string(), (b as u64).into());
+        Ok(auxiliary)
+    }
 }
 
-fn solve_part1(input: &[String]) -> AdventResult<()> {
-    let shuffles = input
-        .iter()
-        .map(parse_shuffle)
-        .collect::<AdventResult<Vec<_>>>()?;
-    let res = shuffle_index(2019, 10007, &shuffles);
-    println!("New index for card 2019: {}", res);
-    Ok(())
+/// The shuffle process and the decks it is applied to. Part 1 follows `card` through one
+/// shuffle of `deck_size` cards, part 2 looks for the card at `position` after `shuffle_count`
+/// shuffles of `big_deck_size` cards. Part 2 inverts the shuffle, `big_deck_size` must be prime.
+pub struct Shuffling {
+    shuffles: Vec<Shuffle>,
+    deck_size: usize,
+    card: usize,
+    big_deck_size: i128,
+    shuffle_count: i128,
+    position: i128,
 }
 
-fn solve_part2(input: &[String]) -> AdventResult<()> {
-    // The deck size and number of shuffles are both prime
-    let deck_size: i128 = 119_315_717_514_047;
-    let number_of_shuffles: i128 = 101_741_582_076_661;
-    let shuffles = input
-        .iter()
-        .map(parse_shuffle)
-        .collect::<AdventResult<Vec<_>>>()?;
-    let res = calculate_part2(2020, deck_size, number_of_shuffles, &shuffles);
-    println!("Compound: {:?}", res);
-    Ok(())
+fn solve_part1(shuffling: &Shuffling) -> AdventResult<usize> {
+    Ok(shuffle_index(
+        shuffling.card,
+        shuffling.deck_size,
+        &shuffling.shuffles,
+    ))
+}
+
+fn solve_part2(shuffling: &Shuffling) -> AdventResult<i128> {
+    Ok(calculate_part2(
+        shuffling.position,
+        shuffling.big_deck_size,
+        shuffling.shuffle_count,
+        &shuffling.shuffles,
+    ))
 }
 
 #[derive(Clone, Copy)]
-enum Shuffle {
+pub enum Shuffle {
     DealIntoNewStack,
     CutFromTop(usize),
     CutFromBottom(usize),
@@ -56,7 +128,7 @@ fn parse_shuffle<T: AsRef<str>>(input: T) -> AdventResult<Shuffle> {
 
     if input.contains("increment") {
         let n: usize = input.split_whitespace().last().expect("Number!").parse()?;
-        return Ok(Shuffle::DealWithIncrement(n as usize));
+        return Ok(Shuffle::DealWithIncrement(n));
     }
 
     Err(AdventError::InvalidValue)
@@ -67,8 +139,8 @@ fn shuffle_index(init_index: usize, deck_size: usize, shuffle: &[Shuffle]) -> us
         .iter()
         .fold(init_index, |index, technique| match *technique {
             Shuffle::DealIntoNewStack => deck_size - (index + 1),
-            Shuffle::CutFromTop(n) => (deck_size + index - n) % deck_size,
-            Shuffle::CutFromBottom(n) => (index + n) % deck_size,
+            Shuffle::CutFromTop(n) => (deck_size + index - n % deck_size) % deck_size,
+            Shuffle::CutFromBottom(n) => (index + n % deck_size) % deck_size,
             Shuffle::DealWithIncrement(n) => (index * n) % deck_size,
         })
 }
@@ -120,6 +192,33 @@ fn mod_exp(a: i128, b: i128, m: i128) -> i128 {
     res
 }
 
+/// Miller-Rabin test, deterministic with these bases for every `n` below 3.3 * 10^24.
+fn is_prime(n: i128) -> bool {
+    const BASES: [i128; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+    if n < 2 {
+        return false;
+    }
+    if let Some(&base) = BASES.iter().find(|&&base| n % base == 0) {
+        return n == base;
+    }
+    let mut d = n - 1;
+    let mut s = 0;
+    while d % 2 == 0 {
+        d /= 2;
+        s += 1;
+    }
+    BASES.iter().all(|&base| {
+        let mut x = mod_exp(base, d, n);
+        if x == 1 || x == n - 1 {
+            return true;
+        }
+        (1..s).any(|_| {
+            x = x * x % n;
+            x == n - 1
+        })
+    })
+}
+
 /// Inverse prime modulo of a mod d
 /// d is prime so a^-1 mod d = a^(\phi{d}-1) = a^(d-2)
 fn mod_inv_prime(a: i128, d: i128) -> i128 {
@@ -130,7 +229,7 @@ fn mod_inv_prime(a: i128, d: i128) -> i128 {
 /// x: index, d: deck size, n: number of shuffle
 /// We represent the shuffle as a linear transformation of the initial index `x`: (ax + b) mod d
 /// Applying the shuffle n times is just a compound of this:
-/// ```
+/// ```text
 /// index = a*(a*(a*...(ax+b)+b)+b)+b = a^n*x + b(1+a+a^2+...+a^(n-1))
 /// index = a^n*x + b ( a^n - 1 ) * mod_inv(a - 1)`  mod d
 ///       = a^n ( x + b * mod_inv(a - 1, d) ) - b * mod_inv(a - 1, d)
@@ -138,17 +237,16 @@ fn mod_inv_prime(a: i128, d: i128) -> i128 {
 /// where `A = a^n mod d` and `B = b * mod_inv(a - 1, d)  mod d`
 /// ```
 /// Looking for the final value at position `index`
-/// ```
+/// ```text
 /// index = A * (x + B) - B mod d <=> x = (index + B) * mod_inv(A, d) - B
 /// mod_inv(A, d) = mod_inv_prime(a^n) = mod_inv_prime(a)^n
 /// ```
 /// Thus:
-/// ```
+/// ```text
 /// x = (index + B) * mod_inv_prime(a)^n - b
 /// ```
 fn calculate_part2(index: i128, d: i128, n: i128, shuffle: &[Shuffle]) -> i128 {
     let (a, b) = compound_shuffle(d, shuffle);
-    println!("Compound shuffle ax + b: (a, b) = ({}, {})", a, b);
     let a_inv = mod_exp(a - 1, d - 2, d);
     let b = (b * a_inv) % d;
     ((index + b) * mod_exp(mod_inv_prime(a, d), n, d) - b) % d
@@ -226,14 +324,14 @@ fn test_day22_case2() {
 
 #[cfg(test)]
 fn shuffle_index_compounded(index: i128, deck_size: i128, shuffle: &[Shuffle]) -> i128 {
-    let (a, b) = compound_shuffle(deck_size as i128, shuffle);
+    let (a, b) = compound_shuffle(deck_size, shuffle);
     println!("Compound shuffle ax + b: (a, b) = ({}, {})", a, b);
     (a * index + b).rem_euclid(deck_size)
 }
 
 #[test]
 fn test_day22_part2_equals_part1() -> AdventResult<()> {
-    let input = &get_input::<String>(22)?.first_column();
+    let input = &get_input::<String>(22)?.first_column()?;
     let shuffles = input
         .iter()
         .map(parse_shuffle)
@@ -243,3 +341,22 @@ fn test_day22_part2_equals_part1() -> AdventResult<()> {
     assert_eq!(res as i128, res_compound);
     Ok(())
 }
+
+#[test]
+fn test_day22_big_deck_must_be_prime() {
+    assert!(is_prime(119_315_717_514_047));
+    assert!(is_prime(10007));
+    assert!(!is_prime(119_315_717_514_045));
+    // Strong pseudoprime to bases 2, 3, 5 and 7
+    assert!(!is_prime(3_215_031_751));
+    assert!(!is_prime(1));
+
+    let mut params = Params::new();
+    params.insert("big_deck_size", "10006");
+    assert!(matches!(
+        Day22::parse_with("deal into new stack", &params),
+        Err(AdventError::InvalidParam(key)) if key == "big_deck_size"
+    ));
+    params.insert("big_deck_size", "10007");
+    assert!(Day22::parse_with("deal into new stack", &params).is_ok());
+}
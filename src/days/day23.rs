@@ -1,23 +1,32 @@
-use advent2019::intcode;
-use advent2019::{get_input, AdventResult};
+use crate::intcode;
+use crate::solution::Solution;
+use crate::{parse_input, AdventResult};
 use std::collections::VecDeque;
 use std::fmt;
 
-fn main() -> AdventResult<()> {
-    let program = &get_input::<isize>(23)?.first_row();
-    solve_part1(program);
-    solve_part2(program);
-    Ok(())
-}
+pub struct Day23;
 
-fn solve_part1(input: &[isize]) {
-    let mut network = Network::new(input);
-    network.run(false)
-}
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+    type Input = Vec<isize>;
+    type Part1 = isize;
+    type Part2 = isize;
+
+    fn parse(input: &str) -> AdventResult<Vec<isize>> {
+        parse_input::<isize>(input)?.first_row()
+    }
 
-fn solve_part2(input: &[isize]) {
-    let mut network = Network::new(input);
-    network.run(true)
+    // Y value of the first packet sent to the NAT
+    fn part1(program: &Vec<isize>) -> AdventResult<isize> {
+        let mut network = Network::new(program);
+        Ok(network.run(false).y)
+    }
+
+    // Y value delivered by the NAT twice in a row
+    fn part2(program: &Vec<isize>) -> AdventResult<isize> {
+        let mut network = Network::new(program);
+        Ok(network.run(true).y)
+    }
 }
 
 #[derive(Clone, PartialEq, Eq)]
@@ -61,7 +70,7 @@ impl Network {
         }
     }
 
-    fn run(&mut self, part2: bool) {
+    fn run(&mut self, part2: bool) -> Packet {
         let mut idle_cycles = 0;
         loop {
             for (current_address, intcode) in self.computers.iter_mut().enumerate() {
@@ -89,11 +98,7 @@ impl Network {
 
                             if packet.address == 255 {
                                 if !part2 {
-                                    println!(
-                                        "Packet sent to NAT by {}: {}",
-                                        current_address, &packet
-                                    );
-                                    return;
+                                    return packet;
                                 }
                                 self.nat = Some(packet);
                             } else {
@@ -114,8 +119,7 @@ impl Network {
                     self.computers[0].set_input(&[nat_packet.x, nat_packet.y]);
                     if let Some(last_one) = self.last_sent_from_nat.take() {
                         if nat_packet.y == last_one.y {
-                            println!("Packet Y sent by NAT twice in a row: {}", &nat_packet);
-                            return;
+                            return nat_packet;
                         }
                     }
                     self.last_sent_from_nat = Some(nat_packet);
@@ -0,0 +1,279 @@
+use crate::geometry::{Direction, Point};
+use crate::grid::Grid;
+use crate::render::{self, Frame, Recorder, Rgb};
+use crate::solution::Solution;
+use crate::{AdventError, AdventResult};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+const GRID_SIZE: usize = 5;
+const GRID_ISIZE: isize = GRID_SIZE as isize;
+const CENTER: Point<usize> = Point::new(2, 2);
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+    type Input = Grid<bool>;
+    type Part1 = u32;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> AdventResult<Grid<bool>> {
+        let bugs = Grid::parse_with(input, |c| Ok(c == '#'))?;
+        if bugs.width() != GRID_SIZE || bugs.height() != GRID_SIZE {
+            return Err(AdventError::InvalidValue);
+        }
+        Ok(bugs)
+    }
+
+    fn part1(bugs: &Grid<bool>) -> AdventResult<u32> {
+        let mut eris = Eris::new(bugs);
+        Ok(eris.find_cycle())
+    }
+
+    fn part2(bugs: &Grid<bool>) -> AdventResult<usize> {
+        let mut eris = RecursiveEris::new(bugs);
+        Ok(eris.bugs_after_n_minutes(200))
+    }
+
+    fn record(bugs: &Grid<bool>, recorder: &mut Recorder) -> AdventResult<()> {
+        let mut eris = Eris::new(bugs);
+        eris.find_cycle_with(|eris| recorder.capture(eris));
+        Ok(())
+    }
+}
+
+struct Eris {
+    map: Grid<bool>,
+}
+
+impl Default for Eris {
+    fn default() -> Self {
+        Self {
+            map: Grid::new(GRID_SIZE, GRID_SIZE, false),
+        }
+    }
+}
+
+impl Eris {
+    fn new(map: &Grid<bool>) -> Self {
+        Self { map: map.clone() }
+    }
+
+    fn update_map(&mut self) {
+        let neighbour_count = self.count_neighbours();
+        self.update_map_from_count(neighbour_count);
+    }
+
+    fn count_neighbours(&self) -> Grid<u8> {
+        let mut neighbour_counts = Grid::new(GRID_SIZE, GRID_SIZE, 0);
+        for p in self.map.points() {
+            neighbour_counts[p] = self.map.neighbours4(p).filter(|&n| self.map[n]).count() as u8;
+        }
+        neighbour_counts
+    }
+
+    fn update_map_from_count(&mut self, neighbour_count: Grid<u8>) {
+        for (bug, count) in self.map.values_mut().zip(neighbour_count.values()) {
+            if *bug {
+                if *count != 1 {
+                    *bug = false;
+                }
+            } else if *count == 1 || *count == 2 {
+                *bug = true
+            }
+        }
+    }
+
+    fn hash(&self) -> u32 {
+        self.map
+            .values()
+            .fold((0, 1), |(mut d, mut base), &bug| {
+                if bug {
+                    d += base;
+                }
+                base *= 2;
+                (d, base)
+            })
+            .0
+    }
+
+    fn find_cycle(&mut self) -> u32 {
+        self.find_cycle_with(|_| ())
+    }
+
+    /// `find_cycle`, calling `on_step` with the first layout and after every minute.
+    fn find_cycle_with<F: FnMut(&Eris)>(&mut self, mut on_step: F) -> u32 {
+        let mut history = HashSet::new();
+        let mut hash = 0;
+        on_step(self);
+        while !history.contains(&hash) {
+            history.insert(self.hash());
+            self.update_map();
+            on_step(self);
+            hash = self.hash();
+        }
+        hash
+    }
+
+    fn count_bugs(&self) -> usize {
+        self.map.values().filter(|c| **c).count()
+    }
+}
+
+impl Frame for Eris {
+    fn frame(&self) -> Grid<Rgb> {
+        self.map
+            .map(|&bug| if bug { render::GREEN } else { render::BLACK })
+    }
+}
+
+impl fmt::Display for Eris {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), std::fmt::Error> {
+        let map = render::text(&self.map, |&bug| if bug { '#' } else { '.' });
+        writeln!(f, "{}", map)
+    }
+}
+
+struct RecursiveEris {
+    level: isize,
+    min_level: isize,
+    max_level: isize,
+    recursive_map: HashMap<isize, Eris>,
+}
+
+impl RecursiveEris {
+    fn new(map: &Grid<bool>) -> Self {
+        let mut hm = HashMap::new();
+        hm.insert(0, Eris::new(map));
+        Self {
+            level: 0,
+            min_level: 0,
+            max_level: 0,
+            recursive_map: hm,
+        }
+    }
+
+    fn current_map(&mut self) -> &mut Grid<bool> {
+        &mut self.recursive_map.entry(self.level).or_default().map
+    }
+
+    fn inner_map(&mut self) -> &mut Grid<bool> {
+        self.max_level = self.max_level.max(self.level + 1);
+        &mut self.recursive_map.entry(self.level + 1).or_default().map
+    }
+
+    fn outer_map(&mut self) -> &mut Grid<bool> {
+        self.min_level = self.min_level.min(self.level - 1);
+        &mut self.recursive_map.entry(self.level - 1).or_default().map
+    }
+
+    fn remove_empty_maps(&mut self) {
+        // Remove empty maps
+        let empty_levels: Vec<_> = self
+            .recursive_map
+            .iter()
+            .filter(|(_, v)| v.hash() == 0)
+            .map(|(k, _)| *k)
+            .collect();
+        for level in empty_levels {
+            self.recursive_map.remove(&level);
+        }
+    }
+
+    /// Bugs on the edge of the inner level facing a tile moving `dir` into the center.
+    fn inner_edge_count(&mut self, dir: Direction) -> u8 {
+        let inner = self.inner_map();
+        let last = GRID_SIZE - 1;
+        let edge: Vec<bool> = match dir {
+            Direction::Down => inner.row(0).unwrap_or_default().to_vec(),
+            Direction::Up => inner.row(last).unwrap_or_default().to_vec(),
+            Direction::Right => inner.column(0).into_iter().flatten().copied().collect(),
+            Direction::Left => inner.column(last).into_iter().flatten().copied().collect(),
+        };
+        edge.into_iter().filter(|&bug| bug).count() as u8
+    }
+
+    fn adjacent_bug_count(&mut self, loc: Point<usize>) -> u8 {
+        let mut adjacent_bug = 0;
+        for &dir in Direction::ALL.iter() {
+            let p = loc.signed() + dir.offset();
+            adjacent_bug += match (p.x, p.y) {
+                (2, 2) => self.inner_edge_count(dir),
+                (-1, _) => self.outer_map()[CENTER.step(Direction::Left).unwrap()] as u8,
+                (GRID_ISIZE, _) => self.outer_map()[CENTER.step(Direction::Right).unwrap()] as u8,
+                (_, -1) => self.outer_map()[CENTER.step(Direction::Up).unwrap()] as u8,
+                (_, GRID_ISIZE) => self.outer_map()[CENTER.step(Direction::Down).unwrap()] as u8,
+                _ => self.current_map()[p.unsigned().unwrap()] as u8,
+            };
+        }
+        adjacent_bug
+    }
+
+    fn count_neighbours(&mut self) -> Grid<u8> {
+        let mut neighbour_counts = Grid::new(GRID_SIZE, GRID_SIZE, 0);
+        for p in neighbour_counts.points() {
+            if p != CENTER {
+                neighbour_counts[p] = self.adjacent_bug_count(p);
+            }
+        }
+        neighbour_counts
+    }
+
+    fn recursive_update(&mut self) {
+        let mut level_counts = HashMap::new();
+        for level in self.min_level - 1..=self.max_level + 1 {
+            self.level = level;
+            let neighbour_count = self.count_neighbours();
+            level_counts.insert(self.level, neighbour_count);
+        }
+
+        for (level, count) in level_counts.into_iter() {
+            if let Some(eris) = self.recursive_map.get_mut(&level) {
+                eris.update_map_from_count(count);
+            }
+        }
+    }
+
+    fn count_bugs(&self) -> usize {
+        self.recursive_map
+            .values()
+            .map(|eris| eris.count_bugs())
+            .sum()
+    }
+
+    fn bugs_after_n_minutes(&mut self, n: usize) -> usize {
+        for _ in 0..n {
+            self.recursive_update();
+        }
+        self.remove_empty_maps();
+        self.count_bugs()
+    }
+}
+
+impl fmt::Display for RecursiveEris {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), std::fmt::Error> {
+        let mut keys: Vec<_> = self.recursive_map.keys().collect();
+        keys.sort();
+
+        keys.iter()
+            .try_for_each(|level| writeln!(f, "Level {}:\n{}", level, self.recursive_map[level]))
+    }
+}
+
+#[test]
+fn test_day24_case1() {
+    let bugs = &["....#", "#..#.", "#..##", "..#..", "#...."];
+    let bugs = Grid::parse_with(&bugs.join("\n"), |c| Ok(c == '#')).unwrap();
+    let mut eris = Eris::new(&bugs);
+    assert_eq!(eris.find_cycle(), 2_129_920);
+}
+
+#[test]
+fn test_day24_case2() {
+    let bugs = &["....#", "#..#.", "#.?##", "..#..", "#...."];
+    let bugs = Grid::parse_with(&bugs.join("\n"), |c| Ok(c == '#')).unwrap();
+    let mut eris = RecursiveEris::new(&bugs);
+    assert_eq!(eris.bugs_after_n_minutes(10), 99);
+    println!("{}", eris);
+}
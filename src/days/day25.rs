@@ -0,0 +1,289 @@
+use crate::intcode::IntCode;
+use crate::solution::{Params, Solution};
+use crate::tui::{AsciiTerminal, Session};
+use crate::{parse_input, AdventError, AdventResult};
+use regex::Regex;
+use std::collections::HashSet;
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+    type Input = Ship;
+    type Part1 = String;
+    type Part2 = &'static str;
+
+    const PARAMS: &'static [&'static str] = &["route"];
+
+    fn parse(input: &str) -> AdventResult<Ship> {
+        Self::parse_with(input, &Params::new())
+    }
+
+    fn parse_with(input: &str, params: &Params) -> AdventResult<Ship> {
+        let route = params
+            .get_str("route")
+            .map(|route| route.split(',').map(|c| c.trim().to_string()).collect());
+        Ok(Ship {
+            program: parse_input::<isize>(input)?.first_row()?,
+            route,
+        })
+    }
+
+    fn part1(ship: &Ship) -> AdventResult<String> {
+        match &ship.route {
+            Some(route) => solve(&ship.program, route),
+            None => explore(&ship.program),
+        }
+    }
+
+    // There is no second puzzle on Christmas day
+    fn part2(_ship: &Ship) -> AdventResult<&'static str> {
+        Ok("Merry Christmas!")
+    }
+
+    // `advent play 25` to explore the ship by hand
+    fn console(ship: &Ship) -> Option<Session> {
+        Some(Session::new(&ship.program, AsciiTerminal::new()))
+    }
+}
+
+/// The droid's program, and optionally a known route to replay instead of exploring the ship.
+pub struct Ship {
+    program: Vec<isize>,
+    route: Option<Vec<String>>,
+}
+
+// Taking any of these ends the game or traps the droid
+const DANGEROUS_ITEMS: &[&str] = &[
+    "giant electromagnet",
+    "molten lava",
+    "infinite loop",
+    "escape pod",
+    "photons",
+];
+
+/// A room as last described by the droid.
+#[derive(Debug, PartialEq, Eq)]
+struct Room {
+    name: String,
+    doors: Vec<String>,
+    items: Vec<String>,
+}
+
+impl Room {
+    /// Reads the last room described in `output`: after a failed weighing the droid is pushed back
+    /// and both rooms are printed.
+    fn parse(output: &str) -> Option<Room> {
+        let description = &output[output.rfind("== ")?..];
+        let name = description[3..].split(" ==").next()?.to_string();
+        let list = |title: &str| -> Vec<String> {
+            description
+                .split(title)
+                .nth(1)
+                .map(|rest| {
+                    rest.lines()
+                        .skip(1)
+                        .map_while(|line| line.strip_prefix("- "))
+                        .map(str::to_string)
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+        Some(Room {
+            name,
+            doors: list("Doors here lead:"),
+            items: list("Items here:"),
+        })
+    }
+}
+
+fn opposite(direction: &str) -> &'static str {
+    match direction {
+        "north" => "south",
+        "south" => "north",
+        "east" => "west",
+        _ => "east",
+    }
+}
+
+fn command(droid: &mut IntCode, command: &str) -> String {
+    let mut input: Vec<isize> = command.bytes().map(isize::from).collect();
+    input.push(10);
+    ascii(&droid.run_till_blocked(&input))
+}
+
+fn ascii(output: &[isize]) -> String {
+    output.iter().map(|&c| c as u8 as char).collect()
+}
+
+fn password(output: &str) -> AdventResult<Option<String>> {
+    Ok(Regex::new(r"typing (\d+) on the keypad")?
+        .captures(output)
+        .map(|password| password[1].to_string()))
+}
+
+/// What the droid learnt walking around the ship.
+#[derive(Default)]
+struct Survey {
+    visited: HashSet<String>,
+    inventory: Vec<String>,
+    // Route from the hull breach to the security checkpoint, and the way to the floor from there
+    checkpoint: Option<(Vec<String>, String)>,
+}
+
+/// Walks every room depth first, picking up the safe items and coming back the way it came.
+fn walk(droid: &mut IntCode, room: &Room, route: &mut Vec<String>, survey: &mut Survey) {
+    survey.visited.insert(room.name.clone());
+    for item in room.items.iter() {
+        if DANGEROUS_ITEMS.contains(&item.as_str()) {
+            continue;
+        }
+        command(droid, &format!("take {}", item));
+        survey.inventory.push(item.clone());
+    }
+    for door in room.doors.iter() {
+        let output = command(droid, door);
+        let next = match Room::parse(&output) {
+            Some(next) => next,
+            None => continue,
+        };
+        if next.name == room.name {
+            // Turned back by the pressure-sensitive floor
+            survey.checkpoint = Some((route.clone(), door.clone()));
+            continue;
+        }
+        if !survey.visited.contains(&next.name) {
+            route.push(door.clone());
+            walk(droid, &next, route, survey);
+            route.pop();
+        }
+        command(droid, opposite(door));
+    }
+}
+
+/// Explores the ship with every safe item, then weighs each combination of them on the
+/// pressure-sensitive floor until the droid is let through.
+fn explore(code: &[isize]) -> AdventResult<String> {
+    let mut droid = IntCode::new(code);
+    let start =
+        Room::parse(&ascii(&droid.run_till_blocked(&[]))).ok_or(AdventError::InvalidValue)?;
+    let mut survey = Survey::default();
+    walk(&mut droid, &start, &mut Vec::new(), &mut survey);
+    let (route, floor) = survey.checkpoint.ok_or(AdventError::InvalidValue)?;
+    for door in route.iter() {
+        command(&mut droid, door);
+    }
+
+    let items = survey.inventory;
+    let mut held = vec![true; items.len()];
+    for step in 0..1usize << items.len() {
+        // Gray code order: one item is taken or dropped between two weighings
+        let combination = step ^ (step >> 1);
+        for (i, item) in items.iter().enumerate() {
+            let wanted = combination & (1 << i) != 0;
+            if wanted != held[i] {
+                let action = if wanted { "take" } else { "drop" };
+                command(&mut droid, &format!("{} {}", action, item));
+                held[i] = wanted;
+            }
+        }
+        if let Some(password) = password(&command(&mut droid, &floor))? {
+            return Ok(password);
+        }
+    }
+    Err(AdventError::InvalidValue)
+}
+
+/// Replays `route`, one command per step. Fails when the droid is still waiting for commands at
+/// the end of the route.
+///
+/// The route below was found by hand on this repository's input before the droid could explore
+/// on its own (`--param route=east,east,take semiconductor,...`).
+///
+/// ### Map
+/// The items are represented under the room name as `[item]` when usable and `(item)`when not.
+/// ```text
+///                                    Kitchen
+///                               [weather machine]
+///                                       |
+///                                    Storage
+///                                       |                                                     
+///                                    Sick Bay ----------- Crew quarters
+///                            [space law space brochure]       [jam]
+///                                       |
+///                                       |       Navigation -- Stables
+///                                       |                    [antenna]
+///                                       |                        |
+///   Arcade -- Observatory -- Hot Chocolate Fountaine -- Gift Wrapping Center      Science Lab
+/// [monolith]                       [food ration]           [planetoid]           (molten lava)
+///                                                                |                     |
+///    Corridor                                                    |                     |
+/// (infinite loop)                                                |                     |
+///       |                                                        |                     |
+///  Hull Breach ------------- Engineering ------------- Warp Drive Maintenance ------ Hallway   
+///                                                        [semiconductor]     (giant electromagnet)
+///                                                                                      |
+///                                                                                   Holodeck
+///                                                                                 (escape pod)
+///                                                                                      |
+///                                                                                   Passage -- Security Checkpoint -- Pressure-Sensitive Floor
+///                                                                                  (photons)                  
+/// ```
+///
+/// ### Solution
+/// There is a pressure sensitive door which requires to carry the right objects (to get to the right weight)
+/// The final combination was:
+/// - food ration
+/// - antenna
+/// - semiconductor
+/// - monolith
+///
+/// Leading to the final output:
+/// ```text
+/// == Pressure-Sensitive Floor ==
+/// Analyzing...
+///
+/// Doors here lead:
+/// - west
+///
+/// A loud, robotic voice says "Analysis complete! You may proceed." and you enter the cockpit.
+/// Santa notices your small droid, looks puzzled for a moment, realizes what has happened, and radios your ship directly.
+/// "Oh, hello! You should be able to get in by typing 268468864 on the keypad at the main airlock."
+/// ```
+/// Final set of commands:
+/// ```text
+/// ["east", "east", "take semiconductor",
+///  "north", "north", "take antenna",
+///  "south", "west", "take food ration",
+///  "west", "west", "take monolith",
+///  "east", "east", "east", "south",
+///  "east", "south", "south", "east", "east", ""]
+/// ```
+fn solve(code: &[isize], route: &[String]) -> AdventResult<String> {
+    let mut commands = route.join("\n");
+    commands.push('\n');
+    let commands: Vec<_> = commands.bytes().map(isize::from).collect();
+    let output = ascii(&IntCode::new(code).run_till_blocked(&commands));
+    password(&output)?.ok_or(AdventError::InvalidValue)
+}
+
+#[test]
+fn test_day25_room() {
+    let output = "\n\n\n== Hull Breach ==\nYou got in.\n\nDoors here lead:\n- north\n- east\n\n\
+                  Items here:\n- jam\n\nCommand?\n";
+    let room = Room::parse(output).unwrap();
+    assert_eq!(room.name, "Hull Breach");
+    assert_eq!(room.doors, vec!["north", "east"]);
+    assert_eq!(room.items, vec!["jam"]);
+}
+
+#[test]
+fn test_day25_wrong_route() -> AdventResult<()> {
+    let program = crate::get_input::<isize>(25)?.first_row()?;
+    let route = vec!["east".to_string()];
+    assert!(matches!(
+        solve(&program, &route),
+        Err(AdventError::InvalidValue)
+    ));
+    Ok(())
+}
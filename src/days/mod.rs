@@ -0,0 +1,99 @@
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+use crate::solution::{Params, Runner, SolutionRunner};
+use crate::{AdventError, AdventResult};
+
+macro_rules! days {
+    ($($day:literal => $module:ident :: $solution:ident),* $(,)?) => {
+        pub const ALL: &[u8] = &[$($day),*];
+
+        pub fn runner(day: u8) -> Option<Box<dyn Runner>> {
+            match day {
+                $($day => Some(Box::new(SolutionRunner::<$module::$solution>::new())),)*
+                _ => None,
+            }
+        }
+
+        /// Runner using `params` in place of the puzzle's constants.
+        pub fn runner_with_params(day: u8, params: &Params) -> AdventResult<Box<dyn Runner>> {
+            match day {
+                $($day => Ok(Box::new(SolutionRunner::<$module::$solution>::with_params(
+                    params.clone(),
+                )?)),)*
+                _ => Err(AdventError::UnknownDay(day)),
+            }
+        }
+    };
+}
+
+days! {
+    1 => day01::Day01,
+    2 => day02::Day02,
+    3 => day03::Day03,
+    4 => day04::Day04,
+    5 => day05::Day05,
+    6 => day06::Day06,
+    7 => day07::Day07,
+    8 => day08::Day08,
+    9 => day09::Day09,
+    10 => day10::Day10,
+    11 => day11::Day11,
+    12 => day12::Day12,
+    13 => day13::Day13,
+    14 => day14::Day14,
+    15 => day15::Day15,
+    16 => day16::Day16,
+    17 => day17::Day17,
+    18 => day18::Day18,
+    19 => day19::Day19,
+    20 => day20::Day20,
+    21 => day21::Day21,
+    22 => day22::Day22,
+    23 => day23::Day23,
+    24 => day24::Day24,
+    25 => day25::Day25,
+}
+
+#[test]
+fn test_runner_with_params() {
+    use crate::solution::Part;
+
+    let mut params = Params::new();
+    params.insert("steps", "10");
+    assert!(runner_with_params(12, &params).is_ok());
+    assert!(matches!(
+        runner_with_params(1, &params),
+        Err(AdventError::UnknownParam(key)) if key == "steps"
+    ));
+
+    params.insert("steps", "ten");
+    let runner = runner_with_params(12, &params).unwrap();
+    assert!(matches!(
+        runner.run("<x=1, y=2, z=3>", Part::BOTH),
+        Err(AdventError::InvalidParam(key)) if key == "steps"
+    ));
+}
@@ -0,0 +1,256 @@
+//! 2D points and grid directions shared by the days.
+//!
+//! Coordinates follow the puzzle maps: `x` grows to the right and `y` grows downwards, so
+//! `Direction::Up` decreases `y`.
+use crate::AdventError;
+use num::{CheckedAdd, CheckedSub, One, Signed};
+use std::convert::TryFrom;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Point { x, y }
+    }
+}
+
+impl<T: Copy + Signed + Ord> Point<T> {
+    pub fn manhattan(self, other: Self) -> T {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    pub fn chebyshev(self, other: Self) -> T {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+}
+
+impl<T: Copy + CheckedAdd + CheckedSub + One> Point<T> {
+    /// The adjacent point in `dir`, or `None` if it does not fit in `T` (e.g. left of `x = 0usize`).
+    pub fn step(self, dir: Direction) -> Option<Self> {
+        let one = T::one();
+        Some(match dir {
+            Direction::Up => Point::new(self.x, self.y.checked_sub(&one)?),
+            Direction::Right => Point::new(self.x.checked_add(&one)?, self.y),
+            Direction::Down => Point::new(self.x, self.y.checked_add(&one)?),
+            Direction::Left => Point::new(self.x.checked_sub(&one)?, self.y),
+        })
+    }
+
+    /// Up, right, down and left neighbours, in that order.
+    pub fn neighbours4(self) -> impl Iterator<Item = Self> {
+        Direction::ALL.iter().filter_map(move |&dir| self.step(dir))
+    }
+
+    /// The 4 orthogonal neighbours interleaved with the 4 diagonal ones, clockwise from up.
+    pub fn neighbours8(self) -> impl Iterator<Item = Self> {
+        Direction::ALL.iter().flat_map(move |&dir| {
+            let orthogonal = self.step(dir);
+            let diagonal = orthogonal.and_then(|p| p.step(dir.turn_right()));
+            orthogonal.into_iter().chain(diagonal)
+        })
+    }
+}
+
+impl Point<usize> {
+    pub fn signed(self) -> Point<isize> {
+        Point::new(self.x as isize, self.y as isize)
+    }
+}
+
+impl Point<isize> {
+    /// Grid index of the point, `None` if either coordinate is negative.
+    pub fn unsigned(self) -> Option<Point<usize>> {
+        Some(Point::new(
+            usize::try_from(self.x).ok()?,
+            usize::try_from(self.y).ok()?,
+        ))
+    }
+}
+
+impl<T> From<(T, T)> for Point<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Point { x, y }
+    }
+}
+
+impl<T> From<Point<T>> for (T, T) {
+    fn from(p: Point<T>) -> Self {
+        (p.x, p.y)
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point<T> {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point<T> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for Point<T> {
+    type Output = Self;
+    fn mul(self, k: T) -> Self {
+        Point::new(self.x * k, self.y * k)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Point<T> {
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Point<T> {
+    fn sub_assign(&mut self, other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Clockwise, starting up.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self.index() + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self.index() + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self.index() + 2) % 4]
+    }
+
+    pub fn turn(self, turn: Turn) -> Self {
+        match turn {
+            Turn::Left => self.turn_left(),
+            Turn::Right => self.turn_right(),
+        }
+    }
+
+    /// Unit vector of the direction.
+    pub fn offset<T: Signed>(self) -> Point<T> {
+        match self {
+            Direction::Up => Point::new(T::zero(), -T::one()),
+            Direction::Right => Point::new(T::one(), T::zero()),
+            Direction::Down => Point::new(T::zero(), T::one()),
+            Direction::Left => Point::new(-T::one(), T::zero()),
+        }
+    }
+}
+
+/// Accepts both letters (`U`, `R`, `D`, `L`) and arrows (`^`, `>`, `v`, `<`).
+impl TryFrom<char> for Direction {
+    type Error = AdventError;
+
+    fn try_from(c: char) -> Result<Direction, AdventError> {
+        match c {
+            'U' | '^' => Ok(Direction::Up),
+            'R' | '>' => Ok(Direction::Right),
+            'D' | 'v' => Ok(Direction::Down),
+            'L' | '<' => Ok(Direction::Left),
+            _ => Err(AdventError::InvalidValue),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Turn {
+    Left,
+    Right,
+}
+
+impl Turn {
+    /// The quarter turn going from `from` to `to`, `None` if they are equal or opposite.
+    pub fn between(from: Direction, to: Direction) -> Option<Turn> {
+        if from.turn_left() == to {
+            Some(Turn::Left)
+        } else if from.turn_right() == to {
+            Some(Turn::Right)
+        } else {
+            None
+        }
+    }
+
+    pub fn to_char(self) -> char {
+        match self {
+            Turn::Left => 'L',
+            Turn::Right => 'R',
+        }
+    }
+}
+
+#[test]
+fn test_point_distances() {
+    let a = Point::new(1, -2);
+    let b = Point::new(-3, 5);
+    assert_eq!(a.manhattan(b), 11);
+    assert_eq!(a.chebyshev(b), 7);
+    assert_eq!(a + b, Point::new(-2, 3));
+    assert_eq!(a - b, Point::new(4, -7));
+    assert_eq!(-a * 2, Point::new(-2, 4));
+}
+
+#[test]
+fn test_neighbours() {
+    let origin = Point::new(0usize, 0);
+    let n4: Vec<_> = origin.neighbours4().collect();
+    assert_eq!(n4, vec![Point::new(1, 0), Point::new(0, 1)]);
+    assert_eq!(origin.neighbours8().count(), 3);
+
+    let p = Point::new(0isize, 0);
+    let n8: Vec<_> = p.neighbours8().collect();
+    assert_eq!(n8.len(), 8);
+    assert!(n8.iter().all(|n| n.chebyshev(p) == 1));
+    assert!(p.neighbours4().all(|n| n.manhattan(p) == 1));
+}
+
+#[test]
+fn test_direction_turns() {
+    for &dir in Direction::ALL.iter() {
+        assert_eq!(dir.turn_left().turn_right(), dir);
+        assert_eq!(dir.reverse(), dir.turn_right().turn_right());
+        assert_eq!(Point::new(0, 0).step(dir), Some(dir.offset()));
+        assert_eq!(Turn::between(dir, dir.turn(Turn::Left)), Some(Turn::Left));
+        assert_eq!(Turn::between(dir, dir.reverse()), None);
+    }
+    assert_eq!(Direction::try_from('^').unwrap(), Direction::Up);
+}
@@ -0,0 +1,372 @@
+//! Dense and sparse 2D grids indexed by `geometry::Point`.
+use crate::geometry::Point;
+use crate::{AdventError, AdventResult};
+use std::collections::HashMap;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+/// Fixed size grid stored row-major, indexed by `Point<usize>` with `x` the column and `y` the row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    pub fn transpose(&self) -> Self {
+        let cells = (0..self.width)
+            .flat_map(|x| {
+                self.column(x)
+                    .into_iter()
+                    .flatten()
+                    .cloned()
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells,
+        }
+    }
+
+    /// Quarter turn clockwise.
+    pub fn rotate_right(&self) -> Self {
+        let mut rotated = self.transpose();
+        rotated.flip_horizontal();
+        rotated
+    }
+
+    /// Quarter turn counter-clockwise.
+    pub fn rotate_left(&self) -> Self {
+        let mut rotated = self.transpose();
+        rotated.flip_vertical();
+        rotated
+    }
+}
+
+impl<T> Grid<T> {
+    /// Fails on ragged rows.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> AdventResult<Self> {
+        let width = rows.first().map_or(0, |row| row.len());
+        if rows.iter().any(|row| row.len() != width) {
+            return Err(AdventError::InvalidValue);
+        }
+        Ok(Grid {
+            width,
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Cells given row by row; fails unless they fill whole rows of `width`.
+    pub fn from_vec(width: usize, cells: Vec<T>) -> AdventResult<Self> {
+        if width == 0 || !cells.len().is_multiple_of(width) {
+            return Err(AdventError::InvalidValue);
+        }
+        Ok(Grid {
+            width,
+            height: cells.len() / width,
+            cells,
+        })
+    }
+
+    /// One row per line, each character mapped through `f`. Trailing empty lines are ignored.
+    pub fn parse_with<F>(input: &str, mut f: F) -> AdventResult<Self>
+    where
+        F: FnMut(char) -> AdventResult<T>,
+    {
+        let rows = input
+            .trim_end_matches(['\n', '\r'])
+            .lines()
+            .map(|line| line.chars().map(&mut f).collect())
+            .collect::<AdventResult<Vec<Vec<T>>>>()?;
+        if rows.is_empty() {
+            return Err(AdventError::EmptyInput);
+        }
+        Self::from_rows(rows)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, p: Point<usize>) -> bool {
+        p.x < self.width && p.y < self.height
+    }
+
+    pub fn get(&self, p: Point<usize>) -> Option<&T> {
+        if self.contains(p) {
+            Some(&self.cells[p.y * self.width + p.x])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, p: Point<usize>) -> Option<&mut T> {
+        if self.contains(p) {
+            Some(&mut self.cells[p.y * self.width + p.x])
+        } else {
+            None
+        }
+    }
+
+    /// Like `get`, for points that may have gone negative.
+    pub fn get_signed(&self, p: Point<isize>) -> Option<&T> {
+        self.get(p.unsigned()?)
+    }
+
+    /// Orthogonal neighbours of `p` that are inside the grid.
+    pub fn neighbours4(&self, p: Point<usize>) -> impl Iterator<Item = Point<usize>> + '_ {
+        p.neighbours4().filter(move |&n| self.contains(n))
+    }
+
+    /// Orthogonal and diagonal neighbours of `p` that are inside the grid.
+    pub fn neighbours8(&self, p: Point<usize>) -> impl Iterator<Item = Point<usize>> + '_ {
+        p.neighbours8().filter(move |&n| self.contains(n))
+    }
+
+    /// Every point of the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point<usize>> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point<usize>, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    /// First point, row by row, whose value satisfies `predicate`.
+    pub fn position<P: FnMut(&T) -> bool>(&self, mut predicate: P) -> Option<Point<usize>> {
+        self.iter().find(|(_, v)| predicate(v)).map(|(p, _)| p)
+    }
+
+    pub fn find(&self, value: &T) -> Option<Point<usize>>
+    where
+        T: PartialEq,
+    {
+        self.position(|v| v == value)
+    }
+
+    /// `None` when `y` is out of range, like `get`.
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        (y < self.height).then(|| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `max(1)` keeps `chunks` happy on empty grids
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// `None` when `x` is out of range, like `get`.
+    pub fn column(&self, x: usize) -> Option<impl Iterator<Item = &T>> {
+        (x < self.width).then(|| self.cells.iter().skip(x).step_by(self.width))
+    }
+
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    fn flip_horizontal(&mut self) {
+        let width = self.width.max(1);
+        self.cells.chunks_mut(width).for_each(|row| row.reverse());
+    }
+
+    fn flip_vertical(&mut self) {
+        for y in 0..self.height / 2 {
+            for x in 0..self.width {
+                let other = (self.height - 1 - y) * self.width + x;
+                self.cells.swap(y * self.width + x, other);
+            }
+        }
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = AdventError;
+
+    fn from_str(s: &str) -> AdventResult<Self> {
+        Self::parse_with(s, Ok)
+    }
+}
+
+impl<T> Index<Point<usize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point<usize>) -> &T {
+        self.get(p).expect("Point outside the grid")
+    }
+}
+
+impl<T> IndexMut<Point<usize>> for Grid<T> {
+    fn index_mut(&mut self, p: Point<usize>) -> &mut T {
+        self.get_mut(p).expect("Point outside the grid")
+    }
+}
+
+/// Unbounded grid holding only the cells that were set, e.g. a hull painted by a robot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point<isize>, T>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn get(&self, p: Point<isize>) -> Option<&T> {
+        self.cells.get(&p)
+    }
+
+    pub fn get_mut(&mut self, p: Point<isize>) -> Option<&mut T> {
+        self.cells.get_mut(&p)
+    }
+
+    pub fn insert(&mut self, p: Point<isize>, value: T) -> Option<T> {
+        self.cells.insert(p, value)
+    }
+
+    pub fn remove(&mut self, p: Point<isize>) -> Option<T> {
+        self.cells.remove(&p)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point<isize>, &T)> {
+        self.cells.iter().map(|(p, v)| (*p, v))
+    }
+
+    /// Set orthogonal neighbours of `p`.
+    pub fn neighbours4(&self, p: Point<isize>) -> impl Iterator<Item = (Point<isize>, &T)> {
+        p.neighbours4()
+            .filter_map(move |n| self.cells.get(&n).map(|v| (n, v)))
+    }
+
+    /// Set orthogonal and diagonal neighbours of `p`.
+    pub fn neighbours8(&self, p: Point<isize>) -> impl Iterator<Item = (Point<isize>, &T)> {
+        p.neighbours8()
+            .filter_map(move |n| self.cells.get(&n).map(|v| (n, v)))
+    }
+
+    pub fn find(&self, value: &T) -> Option<Point<isize>>
+    where
+        T: PartialEq,
+    {
+        self.iter().find(|(_, v)| *v == value).map(|(p, _)| p)
+    }
+
+    /// Smallest and largest coordinates of the set cells, `None` when empty.
+    pub fn bounds(&self) -> Option<(Point<isize>, Point<isize>)> {
+        let mut points = self.cells.keys();
+        let first = *points.next()?;
+        Some(points.fold((first, first), |(min, max), p| {
+            (
+                Point::new(min.x.min(p.x), min.y.min(p.y)),
+                Point::new(max.x.max(p.x), max.y.max(p.y)),
+            )
+        }))
+    }
+
+    /// Dense copy covering `bounds`, unset cells filled with `fill`, and the point that maps to
+    /// the grid's origin.
+    pub fn to_grid(&self, fill: T) -> (Grid<T>, Point<isize>)
+    where
+        T: Clone,
+    {
+        let (min, max) = match self.bounds() {
+            Some(bounds) => bounds,
+            None => return (Grid::new(0, 0, fill), Point::default()),
+        };
+        let size = (max - min).unsigned().expect("max >= min");
+        let mut grid = Grid::new(size.x + 1, size.y + 1, fill);
+        for (p, v) in self.iter() {
+            grid[(p - min).unsigned().expect("p >= min")] = v.clone();
+        }
+        (grid, min)
+    }
+}
+
+#[test]
+fn test_grid_parse_and_views() {
+    let grid: Grid<char> = "ab\ncd\nef\n".parse().unwrap();
+    assert_eq!((grid.width(), grid.height()), (2, 3));
+    assert_eq!(grid[Point::new(1, 2)], 'f');
+    assert_eq!(grid.get(Point::new(2, 0)), None);
+    assert_eq!(grid.get_signed(Point::new(-1, 0)), None);
+    assert_eq!(grid.find(&'d'), Some(Point::new(1, 1)));
+    assert_eq!(grid.row(1), Some(&['c', 'd'][..]));
+    assert_eq!(grid.row(3), None);
+    assert_eq!(grid.column(1).unwrap().collect::<String>(), "bdf");
+    assert!(grid.column(2).is_none());
+    assert_eq!(grid.neighbours4(Point::new(0, 0)).count(), 2);
+    assert_eq!(grid.neighbours8(Point::new(1, 1)).count(), 5);
+    assert!("ab\nc".parse::<Grid<char>>().is_err());
+}
+
+#[test]
+fn test_grid_rotations() {
+    let grid: Grid<char> = "ab\ncd\nef".parse().unwrap();
+    let rows = |g: &Grid<char>| {
+        g.rows()
+            .map(|r| r.iter().collect())
+            .collect::<Vec<String>>()
+    };
+    assert_eq!(rows(&grid.transpose()), vec!["ace", "bdf"]);
+    assert_eq!(rows(&grid.rotate_right()), vec!["eca", "fdb"]);
+    assert_eq!(rows(&grid.rotate_left()), vec!["bdf", "ace"]);
+    assert_eq!(grid.rotate_right().rotate_left(), grid);
+}
+
+#[test]
+fn test_sparse_grid() {
+    let mut hull = SparseGrid::new();
+    hull.insert(Point::new(-1, 2), 1);
+    hull.insert(Point::new(1, -1), 0);
+    assert_eq!(hull.bounds(), Some((Point::new(-1, -1), Point::new(1, 2))));
+    let (grid, origin) = hull.to_grid(9);
+    assert_eq!(origin, Point::new(-1, -1));
+    assert_eq!((grid.width(), grid.height()), (3, 4));
+    assert_eq!(grid[Point::new(0, 3)], 1);
+    assert_eq!(grid[Point::new(2, 0)], 0);
+    assert_eq!(hull.neighbours8(Point::new(0, 1)).count(), 1);
+}
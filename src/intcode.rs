@@ -1,3 +1,4 @@
+use crate::{AdventError, AdventResult};
 use std::collections::{HashMap, VecDeque};
 use std::iter::FromIterator;
 
@@ -31,6 +32,7 @@ pub struct IntCode {
     pub input: VecDeque<isize>,
     output: Vec<isize>,
     program_halted: bool,
+    instruction_count: usize,
 }
 
 impl IntCode {
@@ -42,6 +44,7 @@ impl IntCode {
             input: VecDeque::with_capacity(1000),
             output: Vec::with_capacity(1000),
             program_halted: false,
+            instruction_count: 0,
         };
         computer.set_program(program);
         computer
@@ -78,6 +81,32 @@ impl IntCode {
         self.program_halted
     }
 
+    /// Value at `address`, e.g. a result left in memory by a program without output.
+    pub fn peek(&self, address: usize) -> isize {
+        self.read(address)
+    }
+
+    /// Number of instructions executed so far.
+    pub fn instruction_count(&self) -> usize {
+        self.instruction_count
+    }
+
+    /// Stopped on an input instruction with nothing left to read.
+    pub fn is_waiting_for_input(&self) -> bool {
+        let input_op = 3;
+        !self.has_halted() && self.get_instruction() % 100 == input_op && self.is_input_empty()
+    }
+
+    /// Runs until the program halts or waits for input it was not given, returning the output
+    /// produced in the meantime.
+    pub fn run_till_blocked(&mut self, input: &[isize]) -> Vec<isize> {
+        self.set_input(input);
+        while !self.has_halted() && !self.is_waiting_for_input() {
+            self.run_instruction();
+        }
+        self.output.drain(..).collect()
+    }
+
     pub fn run_till_halt(&mut self, input: &[isize]) {
         self.set_input(input);
         while !self.has_halted() {
@@ -85,6 +114,19 @@ impl IntCode {
         }
     }
 
+    /// Like `run_till_halt`, but fails with `InvalidValue` instead of panicking on an unknown
+    /// instruction or when the program waits for input it was not given.
+    pub fn try_run_till_halt(&mut self, input: &[isize]) -> AdventResult<()> {
+        self.set_input(input);
+        while !self.has_halted() {
+            if !self.is_supported() || self.is_waiting_for_input() {
+                return Err(AdventError::InvalidValue);
+            }
+            self.run_instruction();
+        }
+        Ok(())
+    }
+
     pub fn run_till_input(&mut self, input: &[isize]) -> &[isize] {
         self.set_input(input);
         self.run_instruction();
@@ -137,6 +179,7 @@ impl IntCode {
     }
 
     fn run_instruction(&mut self) {
+        self.instruction_count += 1;
         let op = self.get_instruction() % 100;
         match op {
             1 => self.add(),
@@ -153,6 +196,26 @@ impl IntCode {
         };
     }
 
+    /// Known op code and parameter modes for the next instruction.
+    fn is_supported(&self) -> bool {
+        let instruction = self.get_instruction();
+        let params = match instruction % 100 {
+            1 | 2 | 7 | 8 => 3,
+            5 | 6 => 2,
+            3 | 4 | 9 => 1,
+            99 => 0,
+            _ => return false,
+        };
+        let mut modes = instruction / 100;
+        for _ in 0..params {
+            if modes % 10 > 2 {
+                return false;
+            }
+            modes /= 10;
+        }
+        true
+    }
+
     fn read(&self, loc: usize) -> isize {
         match self.memory.get(&loc) {
             Some(m) => *m,
@@ -1,4 +1,15 @@
+pub mod bench;
+pub mod days;
+pub mod geometry;
+pub mod grid;
 pub mod intcode;
+pub mod ocr;
+pub mod parallel;
+pub mod render;
+pub mod report;
+pub mod search;
+pub mod solution;
+pub mod tui;
 
 use std::{error, fmt, fs, num, str::FromStr};
 
@@ -6,21 +17,78 @@ use std::{error, fmt, fs, num, str::FromStr};
 pub enum AdventError {
     Request(reqwest::Error),
     Parse(std::num::ParseIntError),
+    /// `line` and `field` count from 1.
+    InvalidField {
+        line: u64,
+        field: usize,
+        text: String,
+        source: Box<AdventError>,
+    },
+    Csv(csv::Error),
+    Regex(regex::Error),
+    EmptyInput,
+    UnknownDay(u8),
     InvalidValue,
     Infallible(std::convert::Infallible),
     IoError(std::io::Error),
+    Image(png::EncodingError),
+    Gif(gif::EncodingError),
+    UnrecognizedGlyphs(Vec<usize>),
+    UnknownParam(String),
+    InvalidParam(String),
+    Overflow,
+    /// Input that parsed but breaks a rule of the puzzle, explained by the boxed error.
+    InvalidInput(Box<dyn error::Error + Send + Sync>),
 }
 
-impl error::Error for AdventError {}
+impl error::Error for AdventError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            AdventError::Request(err) => Some(err),
+            AdventError::Parse(err) => Some(err),
+            AdventError::InvalidField { source, .. } => Some(source.as_ref()),
+            AdventError::Csv(err) => Some(err),
+            AdventError::Regex(err) => Some(err),
+            AdventError::IoError(err) => Some(err),
+            AdventError::Image(err) => Some(err),
+            AdventError::Gif(err) => Some(err),
+            AdventError::InvalidInput(err) => Some(err.as_ref()),
+            _ => None,
+        }
+    }
+}
 
 impl fmt::Display for AdventError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             AdventError::Request(err) => write!(f, "Request failed with error: {}", err),
             AdventError::Parse(err) => write!(f, "Invalid Int: {}", err),
+            AdventError::InvalidField {
+                line,
+                field,
+                text,
+                source,
+            } => write!(
+                f,
+                "Invalid field {} on line {}: {:?} ({})",
+                field, line, text, source
+            ),
+            AdventError::Csv(err) => write!(f, "Invalid CSV: {}", err),
+            AdventError::Regex(err) => write!(f, "Invalid regex: {}", err),
+            AdventError::EmptyInput => write!(f, "Empty input"),
+            AdventError::UnknownDay(day) => write!(f, "No solution for day {}", day),
             AdventError::InvalidValue => write!(f, "Invalid value"),
             AdventError::Infallible(_) => write!(f, "Impossible!"),
             AdventError::IoError(err) => write!(f, "Error: {}", err),
+            AdventError::Image(err) => write!(f, "Could not encode image: {}", err),
+            AdventError::Gif(err) => write!(f, "Could not encode animation: {}", err),
+            AdventError::UnrecognizedGlyphs(columns) => {
+                write!(f, "Unrecognized letters at columns {:?}", columns)
+            }
+            AdventError::UnknownParam(key) => write!(f, "Unknown parameter {:?}", key),
+            AdventError::InvalidParam(key) => write!(f, "Invalid value for parameter {:?}", key),
+            AdventError::Overflow => write!(f, "Arithmetic overflow"),
+            AdventError::InvalidInput(err) => write!(f, "Invalid input: {}", err),
         }
     }
 }
@@ -37,6 +105,18 @@ impl From<num::ParseIntError> for AdventError {
     }
 }
 
+impl From<csv::Error> for AdventError {
+    fn from(err: csv::Error) -> AdventError {
+        AdventError::Csv(err)
+    }
+}
+
+impl From<regex::Error> for AdventError {
+    fn from(err: regex::Error) -> AdventError {
+        AdventError::Regex(err)
+    }
+}
+
 impl From<std::convert::Infallible> for AdventError {
     fn from(err: std::convert::Infallible) -> AdventError {
         AdventError::Infallible(err)
@@ -49,6 +129,18 @@ impl From<std::io::Error> for AdventError {
     }
 }
 
+impl From<png::EncodingError> for AdventError {
+    fn from(err: png::EncodingError) -> AdventError {
+        AdventError::Image(err)
+    }
+}
+
+impl From<gif::EncodingError> for AdventError {
+    fn from(err: gif::EncodingError) -> AdventError {
+        AdventError::Gif(err)
+    }
+}
+
 pub type AdventResult<T> = std::result::Result<T, AdventError>;
 
 pub fn file_name(day: u8) -> String {
@@ -63,7 +155,7 @@ pub fn download_input(day: u8) -> AdventResult<String> {
         .send()?
         .text()
         .map_err(AdventError::from)?;
-    fs::write(file_name(day), &input).expect("Unable to write file");
+    fs::write(file_name(day), &input)?;
     Ok(input)
 }
 
@@ -75,10 +167,19 @@ where
 {
     let data: AdventResult<_> = reader
         .records()
-        .map(|r| r.expect("csv record"))
         .map(|r| {
+            let r = r?;
+            let line = r.position().map_or(0, |position| position.line());
             r.iter()
-                .map(|d| d.parse::<T>().map_err(AdventError::from))
+                .enumerate()
+                .map(|(field, d)| {
+                    d.parse::<T>().map_err(|err| AdventError::InvalidField {
+                        line,
+                        field: field + 1,
+                        text: d.to_string(),
+                        source: Box::new(AdventError::from(err)),
+                    })
+                })
                 .collect::<AdventResult<Vec<T>>>()
         })
         .collect();
@@ -98,12 +199,7 @@ where
     <T as FromStr>::Err: fmt::Debug,
     AdventError: std::convert::From<<T as std::str::FromStr>::Err>,
 {
-    let input = get_raw_input(day)?;
-
-    let reader = csv::ReaderBuilder::new()
-        .has_headers(false)
-        .from_reader(input.as_bytes());
-    parse_csv::<T>(reader)
+    parse_input(&get_raw_input(day)?)
 }
 
 pub fn get_input_with_params<T>(
@@ -116,10 +212,32 @@ where
     <T as FromStr>::Err: fmt::Debug,
     AdventError: std::convert::From<<T as std::str::FromStr>::Err>,
 {
-    let input = get_raw_input(day)?;
+    parse_input_with_params(&get_raw_input(day)?, has_header, delimiter)
+}
 
+pub fn parse_input<T>(input: &str) -> AdventResult<Input<T>>
+where
+    T: FromStr,
+    <T as FromStr>::Err: fmt::Debug,
+    AdventError: std::convert::From<<T as std::str::FromStr>::Err>,
+{
+    parse_input_with_params(input, false, ',')
+}
+
+pub fn parse_input_with_params<T>(
+    input: &str,
+    has_header: bool,
+    delimiter: char,
+) -> AdventResult<Input<T>>
+where
+    T: FromStr,
+    <T as FromStr>::Err: fmt::Debug,
+    AdventError: std::convert::From<<T as std::str::FromStr>::Err>,
+{
+    // Rows may differ in length, e.g. day 3's two wires
     let reader = csv::ReaderBuilder::new()
         .has_headers(has_header)
+        .flexible(true)
         .delimiter(delimiter as u8)
         .from_reader(input.as_bytes());
 
@@ -135,15 +253,45 @@ impl<T: Clone> Input<T> {
     pub fn get_data(self) -> Vec<Vec<T>> {
         self.data
     }
-    pub fn first_element(self) -> T {
-        self.data[0][0].clone()
+    pub fn first_element(self) -> AdventResult<T> {
+        self.data
+            .first()
+            .and_then(|row| row.first())
+            .cloned()
+            .ok_or(AdventError::EmptyInput)
     }
 
-    pub fn first_row(self) -> Vec<T> {
-        self.data[0].to_vec()
+    pub fn first_row(self) -> AdventResult<Vec<T>> {
+        self.data.first().cloned().ok_or(AdventError::EmptyInput)
     }
 
-    pub fn first_column(self) -> Vec<T> {
-        self.data.iter().map(|v| v[0].clone()).collect()
+    pub fn first_column(self) -> AdventResult<Vec<T>> {
+        if self.data.is_empty() {
+            return Err(AdventError::EmptyInput);
+        }
+        self.data
+            .iter()
+            .map(|v| v.first().cloned().ok_or(AdventError::EmptyInput))
+            .collect()
     }
 }
+
+#[test]
+fn test_parse_csv_invalid_field() {
+    let reader = csv::ReaderBuilder::new()
+        .has_headers(false)
+        .from_reader("1,2\n3,x4\n".as_bytes());
+    match parse_csv::<isize>(reader) {
+        Err(err @ AdventError::InvalidField { .. }) => assert_eq!(
+            err.to_string(),
+            "Invalid field 2 on line 2: \"x4\" (Invalid Int: invalid digit found in string)"
+        ),
+        _ => panic!("Expected an invalid field error"),
+    }
+}
+
+#[test]
+fn test_input_empty() {
+    let input = Input::<isize> { data: Vec::new() };
+    assert!(matches!(input.first_row(), Err(AdventError::EmptyInput)));
+}
@@ -0,0 +1,220 @@
+//! Reads the block capitals some puzzles draw, in the 4x6 font (e.g. days 8 and 11) or the
+//! larger 6x10 one.
+//!
+//! Glyphs are told apart by the blank columns between them. Specks that do not span the full
+//! font height, like a stray cell painted by day 11's robot, are ignored.
+use crate::grid::Grid;
+use crate::{AdventError, AdventResult};
+
+const SMALL: &[(char, &str)] = &[
+    ('A', ".##.|#..#|#..#|####|#..#|#..#"),
+    ('B', "###.|#..#|###.|#..#|#..#|###."),
+    ('C', ".##.|#..#|#...|#...|#..#|.##."),
+    ('E', "####|#...|###.|#...|#...|####"),
+    ('F', "####|#...|###.|#...|#...|#..."),
+    ('G', ".##.|#..#|#...|#.##|#..#|.###"),
+    ('H', "#..#|#..#|####|#..#|#..#|#..#"),
+    ('I', ".###|..#.|..#.|..#.|..#.|.###"),
+    ('J', "..##|...#|...#|...#|#..#|.##."),
+    ('K', "#..#|#.#.|##..|#.#.|#.#.|#..#"),
+    ('L', "#...|#...|#...|#...|#...|####"),
+    ('O', ".##.|#..#|#..#|#..#|#..#|.##."),
+    ('P', "###.|#..#|#..#|###.|#...|#..."),
+    ('R', "###.|#..#|#..#|###.|#.#.|#..#"),
+    ('S', ".###|#...|#...|.##.|...#|###."),
+    ('U', "#..#|#..#|#..#|#..#|#..#|.##."),
+    ('Y', "#...#|#...#|.#.#.|..#..|..#..|..#.."),
+    ('Z', "####|...#|..#.|.#..|#...|####"),
+];
+
+const LARGE: &[(char, &str)] = &[
+    (
+        'A',
+        "..##..|.#..#.|#....#|#....#|#....#|######|#....#|#....#|#....#|#....#",
+    ),
+    (
+        'B',
+        "#####.|#....#|#....#|#....#|#####.|#....#|#....#|#....#|#....#|#####.",
+    ),
+    (
+        'C',
+        ".####.|#....#|#.....|#.....|#.....|#.....|#.....|#.....|#....#|.####.",
+    ),
+    (
+        'E',
+        "######|#.....|#.....|#.....|#####.|#.....|#.....|#.....|#.....|######",
+    ),
+    (
+        'F',
+        "######|#.....|#.....|#.....|#####.|#.....|#.....|#.....|#.....|#.....",
+    ),
+    (
+        'G',
+        ".####.|#....#|#.....|#.....|#.....|#..###|#....#|#....#|#...##|.###.#",
+    ),
+    (
+        'H',
+        "#....#|#....#|#....#|#....#|######|#....#|#....#|#....#|#....#|#....#",
+    ),
+    (
+        'J',
+        "...###|....#.|....#.|....#.|....#.|....#.|....#.|#...#.|#...#.|.###..",
+    ),
+    (
+        'K',
+        "#....#|#...#.|#..#..|#.#...|##....|##....|#.#...|#..#..|#...#.|#....#",
+    ),
+    (
+        'L',
+        "#.....|#.....|#.....|#.....|#.....|#.....|#.....|#.....|#.....|######",
+    ),
+    (
+        'N',
+        "#....#|##...#|##...#|#.#..#|#.#..#|#..#.#|#..#.#|#...##|#...##|#....#",
+    ),
+    (
+        'P',
+        "#####.|#....#|#....#|#....#|#####.|#.....|#.....|#.....|#.....|#.....",
+    ),
+    (
+        'R',
+        "#####.|#....#|#....#|#....#|#####.|#..#..|#...#.|#...#.|#....#|#....#",
+    ),
+    (
+        'X',
+        "#....#|#....#|.#..#.|.#..#.|..##..|..##..|.#..#.|.#..#.|#....#|#....#",
+    ),
+    (
+        'Z',
+        "######|.....#|.....#|....#.|...#..|..#...|.#....|#.....|#.....|######",
+    ),
+];
+
+/// A glyph as its columns, top to bottom, without blank columns on either side.
+type Columns = Vec<Vec<bool>>;
+
+fn font_columns(glyph: &str) -> Columns {
+    let rows: Vec<Vec<bool>> = glyph
+        .split('|')
+        .map(|row| row.chars().map(|c| c == '#').collect())
+        .collect();
+    let width = rows[0].len();
+    (0..width)
+        .map(|x| rows.iter().map(|row| row[x]).collect::<Vec<_>>())
+        .filter(|column| column.iter().any(|&on| on))
+        .collect()
+}
+
+/// Reads the letters of `picture`, lit cells being `true`.
+///
+/// Fails with `UnrecognizedGlyphs` listing the starting column of every glyph not in the font.
+pub fn recognize(picture: &Grid<bool>) -> AdventResult<String> {
+    let lit_rows: Vec<usize> = picture
+        .rows()
+        .enumerate()
+        .filter(|(_, row)| row.iter().any(|&on| on))
+        .map(|(y, _)| y)
+        .collect();
+    let (top, bottom) = match (lit_rows.first(), lit_rows.last()) {
+        (Some(&top), Some(&bottom)) => (top, bottom),
+        _ => return Err(AdventError::EmptyInput),
+    };
+    let font = match bottom - top + 1 {
+        6 => SMALL,
+        10 => LARGE,
+        _ => return Err(AdventError::InvalidValue),
+    };
+
+    let columns: Columns = (0..picture.width())
+        .map(|x| {
+            picture
+                .column(x)
+                .into_iter()
+                .flatten()
+                .skip(top)
+                .take(bottom - top + 1)
+                .copied()
+                .collect()
+        })
+        .collect();
+
+    let mut letters = String::new();
+    let mut unrecognized = Vec::new();
+    let mut x = 0;
+    while x < columns.len() {
+        if !columns[x].iter().any(|&on| on) {
+            x += 1;
+            continue;
+        }
+        let start = x;
+        while x < columns.len() && columns[x].iter().any(|&on| on) {
+            x += 1;
+        }
+        let glyph = &columns[start..x];
+        let full_height = glyph.iter().any(|c| c[0]) && glyph.iter().any(|c| c[c.len() - 1]);
+        if !full_height {
+            continue;
+        }
+        match font.iter().find(|(_, g)| font_columns(g) == glyph) {
+            Some((letter, _)) => letters.push(*letter),
+            None => unrecognized.push(start),
+        }
+    }
+
+    if unrecognized.is_empty() {
+        Ok(letters)
+    } else {
+        Err(AdventError::UnrecognizedGlyphs(unrecognized))
+    }
+}
+
+#[cfg(test)]
+fn picture(rows: &[&str]) -> Grid<bool> {
+    Grid::parse_with(&rows.join("\n"), |c| Ok(c == '#')).unwrap()
+}
+
+#[test]
+fn test_ocr_small_font() {
+    let letters = picture(&[
+        ".##..###...##.......#...#",
+        "#..#.#..#.#..#......#...#",
+        "#..#.###..#..........#.#.",
+        "####.#..#.#...........#..",
+        "#..#.#..#.#..#...#....#..",
+        "#..#.###...##.........#..",
+    ]);
+    assert_eq!(recognize(&letters).unwrap(), "ABCY");
+}
+
+#[test]
+fn test_ocr_large_font() {
+    let letters = picture(&[
+        "#....#..#....#",
+        "##...#..#....#",
+        "##...#...#..#.",
+        "#.#..#...#..#.",
+        "#.#..#....##..",
+        "#..#.#....##..",
+        "#..#.#...#..#.",
+        "#...##...#..#.",
+        "#...##..#....#",
+        "#....#..#....#",
+    ]);
+    assert_eq!(recognize(&letters).unwrap(), "NX");
+}
+
+#[test]
+fn test_ocr_unrecognized() {
+    let letters = picture(&[
+        "####.####......####",
+        "#....#..#.........#",
+        "###..#..#........#.",
+        "#....#..#.......#..",
+        "#....#..#......#...",
+        "####.####......####",
+    ]);
+    match recognize(&letters) {
+        Err(AdventError::UnrecognizedGlyphs(columns)) => assert_eq!(columns, vec![5]),
+        other => panic!("Unexpected {:?}", other),
+    }
+}
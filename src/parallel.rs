@@ -0,0 +1,130 @@
+//! Runs independent pieces of work, like the candidates of a brute force search, on every core.
+//!
+//! Workers are scoped threads taking chunks of the input in turn. Results always come back in
+//! input order and `find_map_first` returns the first match in input order, so answers do not
+//! depend on the number of threads or on how the work was scheduled.
+use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Pool {
+    threads: usize,
+}
+
+impl Default for Pool {
+    /// One thread per core.
+    fn default() -> Self {
+        Self::new(thread::available_parallelism().map_or(1, NonZeroUsize::get))
+    }
+}
+
+impl Pool {
+    pub fn new(threads: usize) -> Self {
+        Self {
+            threads: threads.max(1),
+        }
+    }
+
+    pub fn threads(&self) -> usize {
+        self.threads
+    }
+
+    /// `f` applied to every item, in the order of `items`.
+    pub fn map<T, R, F>(&self, items: &[T], f: F) -> Vec<R>
+    where
+        T: Sync,
+        R: Send,
+        F: Fn(&T) -> R + Sync,
+    {
+        let results = Mutex::new(Vec::with_capacity(items.len()));
+        self.for_each_chunk(items.len(), |range| {
+            let chunk: Vec<R> = items[range.clone()].iter().map(&f).collect();
+            results.lock().unwrap().push((range.start, chunk));
+        });
+        let mut results = results.into_inner().unwrap();
+        results.sort_unstable_by_key(|(start, _)| *start);
+        results.into_iter().flat_map(|(_, chunk)| chunk).collect()
+    }
+
+    /// Like `Iterator::find_map`: the result for the first item, in the order of `items`, for
+    /// which `f` returns `Some`. Items after a match already found are skipped.
+    pub fn find_map_first<T, R, F>(&self, items: &[T], f: F) -> Option<R>
+    where
+        T: Sync,
+        R: Send,
+        F: Fn(&T) -> Option<R> + Sync,
+    {
+        let first = AtomicUsize::new(usize::MAX);
+        let found = Mutex::new(Vec::new());
+        self.for_each_chunk(items.len(), |range| {
+            for index in range {
+                if index > first.load(Ordering::Relaxed) {
+                    return;
+                }
+                if let Some(result) = f(&items[index]) {
+                    first.fetch_min(index, Ordering::Relaxed);
+                    found.lock().unwrap().push((index, result));
+                    return;
+                }
+            }
+        });
+        found
+            .into_inner()
+            .unwrap()
+            .into_iter()
+            .min_by_key(|(index, _)| *index)
+            .map(|(_, result)| result)
+    }
+
+    /// Splits `0..len` into chunks handed out to the workers in increasing order.
+    fn for_each_chunk<F>(&self, len: usize, work: F)
+    where
+        F: Fn(std::ops::Range<usize>) + Sync,
+    {
+        // A few chunks per thread so uneven work still spreads
+        let chunk_size = len.div_ceil(self.threads * 4).max(1);
+        let next = AtomicUsize::new(0);
+        let worker = || loop {
+            let start = next.fetch_add(chunk_size, Ordering::Relaxed);
+            if start >= len {
+                break;
+            }
+            work(start..(start + chunk_size).min(len));
+        };
+        if self.threads == 1 || len <= chunk_size {
+            worker();
+            return;
+        }
+        thread::scope(|scope| {
+            for _ in 0..self.threads {
+                scope.spawn(worker);
+            }
+        });
+    }
+}
+
+#[test]
+fn test_parallel_map_keeps_order() {
+    let items: Vec<usize> = (0..1000).collect();
+    let squares: Vec<usize> = items.iter().map(|i| i * i).collect();
+    for threads in [1, 2, 7] {
+        assert_eq!(Pool::new(threads).map(&items, |i| i * i), squares);
+    }
+    assert!(Pool::default().map(&[] as &[usize], |i| *i).is_empty());
+}
+
+#[test]
+fn test_parallel_find_map_first() {
+    let items: Vec<usize> = (0..1000).collect();
+    for threads in [1, 2, 7] {
+        let pool = Pool::new(threads);
+        let multiple = pool.find_map_first(&items, |&i| (i > 0 && i % 97 == 0).then_some(i));
+        assert_eq!(multiple, Some(97));
+        assert_eq!(
+            pool.find_map_first(&items, |&i| (i > 1000).then_some(i)),
+            None
+        );
+    }
+}
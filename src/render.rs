@@ -0,0 +1,319 @@
+//! Renders grids as text or as PPM/PNG images, one cell mapped to one glyph or colour, and
+//! records simulations implementing `Frame` into animated GIFs or numbered PNGs.
+use crate::grid::Grid;
+use crate::{AdventError, AdventResult};
+use std::convert::TryFrom;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+pub type Rgb = [u8; 3];
+
+pub const BLACK: Rgb = [0, 0, 0];
+pub const WHITE: Rgb = [255, 255, 255];
+pub const GREY: Rgb = [128, 128, 128];
+pub const RED: Rgb = [220, 50, 47];
+pub const GREEN: Rgb = [133, 153, 0];
+pub const BLUE: Rgb = [38, 139, 210];
+
+/// One line per row, each cell mapped through `glyph`.
+pub fn text<T, F: FnMut(&T) -> char>(grid: &Grid<T>, mut glyph: F) -> String {
+    grid.rows()
+        .map(|row| row.iter().map(&mut glyph).collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Ppm,
+    Png,
+}
+
+impl Format {
+    pub fn from_path<P: AsRef<Path>>(path: P) -> AdventResult<Format> {
+        match path.as_ref().extension().and_then(|e| e.to_str()) {
+            Some("ppm") => Ok(Format::Ppm),
+            Some("png") => Ok(Format::Png),
+            _ => Err(AdventError::InvalidValue),
+        }
+    }
+
+    fn extension(self) -> &'static str {
+        match self {
+            Format::Ppm => "ppm",
+            Format::Png => "png",
+        }
+    }
+}
+
+/// RGB image where each grid cell is drawn as a `scale` x `scale` square.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    pub fn from_grid<T, F: FnMut(&T) -> Rgb>(grid: &Grid<T>, scale: usize, colour: F) -> Image {
+        let scale = scale.max(1);
+        let colours = grid.map(colour);
+        let width = grid.width() * scale;
+        let mut pixels = Vec::with_capacity(width * grid.height() * scale);
+        for row in colours.rows() {
+            let line: Vec<Rgb> = row
+                .iter()
+                .flat_map(|&c| std::iter::repeat_n(c, scale))
+                .collect();
+            for _ in 0..scale {
+                pixels.extend_from_slice(&line);
+            }
+        }
+        Image {
+            width,
+            height: grid.height() * scale,
+            pixels,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Copy grown to `width` x `height`, anchored top-left and padded with `fill`.
+    fn padded(&self, width: usize, height: usize, fill: Rgb) -> Image {
+        let mut pixels = vec![fill; width * height];
+        for (y, row) in self.pixels.chunks(self.width.max(1)).enumerate() {
+            pixels[y * width..y * width + row.len()].copy_from_slice(row);
+        }
+        Image {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    pub fn pixels(&self) -> &[Rgb] {
+        &self.pixels
+    }
+
+    /// Binary (`P6`) PPM.
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut data = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        data.extend(self.pixels.iter().flatten());
+        data
+    }
+
+    pub fn to_png(&self) -> AdventResult<Vec<u8>> {
+        let size =
+            |length: usize| u32::try_from(length).map_err(|_| png::EncodingError::LimitsExceeded);
+        let (width, height) = (size(self.width)?, size(self.height)?);
+        let mut data = Vec::new();
+        let mut encoder = png::Encoder::new(&mut data, width, height);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&self.pixels.concat())?;
+        writer.finish()?;
+        Ok(data)
+    }
+
+    pub fn encode(&self, format: Format) -> AdventResult<Vec<u8>> {
+        match format {
+            Format::Ppm => Ok(self.to_ppm()),
+            Format::Png => self.to_png(),
+        }
+    }
+
+    /// Writes a `.ppm` or `.png` file, picked from the extension of `path`.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> AdventResult<()> {
+        let data = self.encode(Format::from_path(&path)?)?;
+        fs::write(path, data)?;
+        Ok(())
+    }
+}
+
+/// State of a simulation that can be drawn, one colour per cell.
+pub trait Frame {
+    fn frame(&self) -> Grid<Rgb>;
+}
+
+/// Collects the frames of a running simulation, keeping one step out of `every`.
+pub struct Recorder {
+    scale: usize,
+    every: usize,
+    step: usize,
+    animation: Animation,
+}
+
+impl Default for Recorder {
+    fn default() -> Self {
+        Recorder::new(4, 1)
+    }
+}
+
+impl Recorder {
+    pub fn new(scale: usize, every: usize) -> Self {
+        Recorder {
+            scale,
+            every: every.max(1),
+            step: 0,
+            animation: Animation::new(),
+        }
+    }
+
+    pub fn capture<F: Frame + ?Sized>(&mut self, state: &F) {
+        if self.step.is_multiple_of(self.every) {
+            let image = Image::from_grid(&state.frame(), self.scale, |&c| c);
+            self.animation.push(image);
+        }
+        self.step += 1;
+    }
+
+    pub fn animation(&self) -> &Animation {
+        &self.animation
+    }
+
+    pub fn into_animation(self) -> Animation {
+        self.animation
+    }
+}
+
+/// Sequence of images, e.g. one per step of a simulation.
+#[derive(Debug, Clone, Default)]
+pub struct Animation {
+    frames: Vec<Image>,
+}
+
+impl Animation {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, frame: Image) {
+        self.frames.push(frame)
+    }
+
+    pub fn frames(&self) -> &[Image] {
+        &self.frames
+    }
+
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// Animated GIF looping forever, showing each frame for `delay` hundredths of a second.
+    /// Frames smaller than the largest one are padded with black.
+    pub fn to_gif(&self, delay: u16) -> AdventResult<Vec<u8>> {
+        let width = self.frames.iter().map(Image::width).max().unwrap_or(0);
+        let height = self.frames.iter().map(Image::height).max().unwrap_or(0);
+        // GIF sizes are 16 bits
+        let size = |length: usize| {
+            u16::try_from(length).map_err(|_| {
+                let err = io::Error::new(io::ErrorKind::InvalidInput, "frame too large for a GIF");
+                gif::EncodingError::Io(err)
+            })
+        };
+        let (gif_width, gif_height) = (size(width)?, size(height)?);
+        let mut data = Vec::new();
+        {
+            let mut encoder = gif::Encoder::new(&mut data, gif_width, gif_height, &[])?;
+            encoder.set_repeat(gif::Repeat::Infinite)?;
+            for image in self.frames.iter() {
+                let pixels = image.padded(width, height, BLACK).pixels.concat();
+                let mut frame = gif::Frame::from_rgb_speed(gif_width, gif_height, &pixels, 10);
+                frame.delay = delay;
+                encoder.write_frame(&frame)?;
+            }
+        }
+        Ok(data)
+    }
+
+    /// Writes a GIF if `path` ends in `.gif`, otherwise numbered PNGs into the `path` directory.
+    pub fn save<P: AsRef<Path>>(&self, path: P, delay: u16) -> AdventResult<()> {
+        if path.as_ref().extension().and_then(|e| e.to_str()) == Some("gif") {
+            fs::write(path, self.to_gif(delay)?)?;
+        } else {
+            self.save_frames(path, Format::Png)?;
+        }
+        Ok(())
+    }
+
+    /// Writes `frame_0000.<ext>`, `frame_0001.<ext>`, ... into `dir` and returns their paths.
+    pub fn save_frames<P: AsRef<Path>>(
+        &self,
+        dir: P,
+        format: Format,
+    ) -> AdventResult<Vec<PathBuf>> {
+        fs::create_dir_all(&dir)?;
+        self.frames
+            .iter()
+            .enumerate()
+            .map(|(i, frame)| {
+                let path = dir
+                    .as_ref()
+                    .join(format!("frame_{:04}.{}", i, format.extension()));
+                fs::write(&path, frame.encode(format)?)?;
+                Ok(path)
+            })
+            .collect()
+    }
+}
+
+#[test]
+fn test_render_text() {
+    let grid = Grid::from_rows(vec![vec![1, 0], vec![0, 1]]).unwrap();
+    assert_eq!(text(&grid, |&c| if c == 1 { '#' } else { '.' }), "#.\n.#");
+}
+
+#[test]
+fn test_render_image() {
+    let grid = Grid::from_rows(vec![vec![true, false]]).unwrap();
+    let image = Image::from_grid(&grid, 2, |&on| if on { WHITE } else { BLACK });
+    assert_eq!((image.width(), image.height()), (4, 2));
+    assert_eq!(image.pixels()[..4], [WHITE, WHITE, BLACK, BLACK]);
+
+    let ppm = image.to_ppm();
+    assert!(ppm.starts_with(b"P6\n4 2\n255\n"));
+    assert_eq!(ppm.len(), 11 + 4 * 2 * 3);
+
+    let png = image.to_png().unwrap();
+    assert_eq!(&png[1..4], b"PNG");
+    assert!(Format::from_path("out.gif").is_err());
+}
+
+#[test]
+fn test_recorder() {
+    struct Counter(usize);
+    impl Frame for Counter {
+        fn frame(&self) -> Grid<Rgb> {
+            Grid::new(self.0, 1, WHITE)
+        }
+    }
+
+    let mut recorder = Recorder::new(1, 2);
+    for width in 1..=5 {
+        recorder.capture(&Counter(width));
+    }
+    let animation = recorder.into_animation();
+    let widths: Vec<_> = animation.frames().iter().map(Image::width).collect();
+    assert_eq!(widths, vec![1, 3, 5]);
+
+    let gif = animation.to_gif(5).unwrap();
+    assert!(gif.starts_with(b"GIF89a"));
+    // Logical screen width, little endian, fits the widest frame
+    assert_eq!(&gif[6..8], &[5, 0]);
+
+    let mut wide = Animation::new();
+    wide.push(Image::from_grid(&Grid::new(70_000, 1, WHITE), 1, |&c| c));
+    assert!(matches!(wide.to_gif(5), Err(AdventError::Gif(_))));
+}
@@ -0,0 +1,143 @@
+//! Machine-readable output of `advent --json`.
+//!
+//! The document is a single JSON object. Fields are only ever added, never renamed or removed;
+//! `version` is bumped if that ever has to change.
+//!
+//! ```text
+//! {
+//!   "version": 1,
+//!   "days": [
+//!     {
+//!       "day": 17,                       // 1-25
+//!       "parse_ns": 78051,               // time spent parsing the input, in nanoseconds
+//!       "parts": [
+//!         { "part": 1, "answer": "8084", "time_ns": 14329000 },
+//!         { "part": 2, "answer": "1119775", "time_ns": 37261000 }
+//!       ],
+//!       "auxiliary": {                   // day specific intermediate results, may be empty
+//!         "main": "A,B,A,B,C,B,C,A,C,C",
+//!         "a": "R,12,L,10,L,10",
+//!         "b": "L,6,L,12,R,12,L,4",
+//!         "c": "L,12,R,12,L,6"
+//!       },
+//!       "error": null                    // message when the day failed, parts are then empty
+//!     }
+//!   ]
+//! }
+//! ```
+//!
+//! Answers are always strings so that every day has the same shape. Days answering with letters
+//! drawn on a screen (8 and 11) also report the drawing as `auxiliary.picture`. Day 1 reports the
+//! three modules needing the most fuel as `[mass, fuel]` pairs in `auxiliary.dominant_modules`.
+//! Day 7 reports the best phase settings in `auxiliary.phases` and `auxiliary.feedback_phases`.
+//! Day 10 reports how many asteroids the laser vaporizes on each turn in `auxiliary.rotations`.
+//! Day 22 reports one pass of the shuffle on part 2's deck as the card at index `x` ending up at
+//! `(a * x + b) mod deck_size`: `auxiliary.deck_size` is that deck's size, `auxiliary.a` and
+//! `auxiliary.b` are integers in `0..deck_size`.
+use crate::solution::{Auxiliary, DayReport};
+use crate::AdventError;
+use serde::Serialize;
+
+pub const VERSION: u32 = 1;
+
+#[derive(Serialize)]
+pub struct Report {
+    pub version: u32,
+    pub days: Vec<DayEntry>,
+}
+
+#[derive(Serialize)]
+pub struct DayEntry {
+    pub day: u8,
+    pub parse_ns: Option<u128>,
+    pub parts: Vec<PartEntry>,
+    pub auxiliary: Auxiliary,
+    pub error: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct PartEntry {
+    pub part: u8,
+    pub answer: String,
+    pub time_ns: u128,
+}
+
+impl Report {
+    pub fn new() -> Self {
+        Self {
+            version: VERSION,
+            days: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, report: DayReport, auxiliary: Auxiliary) {
+        self.days.push(DayEntry {
+            day: report.day,
+            parse_ns: Some(report.parse_time.as_nanos()),
+            parts: report
+                .answers
+                .into_iter()
+                .map(|answer| PartEntry {
+                    part: answer.part.number(),
+                    answer: answer.value,
+                    time_ns: answer.time.as_nanos(),
+                })
+                .collect(),
+            auxiliary,
+            error: None,
+        })
+    }
+
+    pub fn push_error(&mut self, day: u8, err: &AdventError) {
+        self.days.push(DayEntry {
+            day,
+            parse_ns: None,
+            parts: Vec::new(),
+            auxiliary: Auxiliary::new(),
+            error: Some(err.to_string()),
+        })
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("Report is always serializable")
+    }
+}
+
+impl Default for Report {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[test]
+fn test_report_schema() {
+    use crate::solution::{Answer, Part};
+    use std::time::Duration;
+
+    let mut report = Report::new();
+    let mut auxiliary = Auxiliary::new();
+    auxiliary.insert("a".to_string(), 96.into());
+    report.push(
+        DayReport {
+            day: 22,
+            parse_time: Duration::from_nanos(10),
+            answers: vec![Answer {
+                day: 22,
+                part: Part::One,
+                value: "7395".to_string(),
+                time: Duration::from_nanos(20),
+            }],
+        },
+        auxiliary,
+    );
+    report.push_error(30, &AdventError::UnknownDay(30));
+
+    let json: serde_json::Value = serde_json::from_str(&report.to_json()).unwrap();
+    assert_eq!(json["version"], 1);
+    assert_eq!(json["days"][0]["parse_ns"], 10);
+    assert_eq!(json["days"][0]["parts"][0]["answer"], "7395");
+    assert_eq!(json["days"][0]["parts"][0]["time_ns"], 20);
+    assert_eq!(json["days"][0]["auxiliary"]["a"], 96);
+    assert_eq!(json["days"][1]["error"], "No solution for day 30");
+    assert!(json["days"][0]["error"].is_null());
+}
@@ -0,0 +1,331 @@
+//! Shortest path searches over implicit graphs: nodes are discovered through a successor function
+//! instead of being stored up front.
+//!
+//! The free functions take closures, like `pathfinding`'s. A day with a graph-like structure can
+//! instead implement `Graph` (unit steps) or `WeightedGraph` and get the searches as methods.
+use num::Zero;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::hash::Hash;
+
+/// Graph where every step costs 1.
+pub trait Graph {
+    type Node: Clone + Eq + Hash;
+
+    fn neighbours(&self, node: &Self::Node) -> Vec<Self::Node>;
+
+    fn bfs<FS>(&self, start: &Self::Node, success: FS) -> Option<Vec<Self::Node>>
+    where
+        FS: FnMut(&Self::Node) -> bool,
+    {
+        bfs(start, |node| self.neighbours(node), success)
+    }
+
+    fn distances(&self, start: &Self::Node) -> HashMap<Self::Node, usize> {
+        flood_fill(start, |node| self.neighbours(node))
+    }
+}
+
+/// Graph whose edges carry a non-negative cost.
+pub trait WeightedGraph {
+    type Node: Clone + Eq + Hash;
+    type Cost: Zero + Ord + Copy;
+
+    fn edges(&self, node: &Self::Node) -> Vec<(Self::Node, Self::Cost)>;
+
+    fn dijkstra<FS>(&self, start: &Self::Node, success: FS) -> Option<(Vec<Self::Node>, Self::Cost)>
+    where
+        FS: FnMut(&Self::Node) -> bool,
+    {
+        dijkstra(start, |node| self.edges(node), success)
+    }
+
+    fn astar<FH, FS>(
+        &self,
+        start: &Self::Node,
+        heuristic: FH,
+        success: FS,
+    ) -> Option<(Vec<Self::Node>, Self::Cost)>
+    where
+        FH: FnMut(&Self::Node) -> Self::Cost,
+        FS: FnMut(&Self::Node) -> bool,
+    {
+        astar(start, |node| self.edges(node), heuristic, success)
+    }
+}
+
+/// Nodes from `start` to the first node satisfying `success`, both included, in the fewest steps.
+pub fn bfs<N, FN, IN, FS>(start: &N, mut successors: FN, mut success: FS) -> Option<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FS: FnMut(&N) -> bool,
+{
+    // `nodes` doubles as the queue: everything past `current` is still to be expanded
+    let mut nodes = vec![start.clone()];
+    let mut parents = vec![usize::MAX];
+    let mut seen = HashSet::new();
+    seen.insert(start.clone());
+    let mut current = 0;
+    while current < nodes.len() {
+        if success(&nodes[current]) {
+            return Some(reconstruct(&nodes, &parents, current));
+        }
+        for next in successors(&nodes[current]) {
+            if seen.insert(next.clone()) {
+                nodes.push(next);
+                parents.push(current);
+            }
+        }
+        current += 1;
+    }
+    None
+}
+
+/// Number of steps from `start` to every node reachable from it.
+pub fn flood_fill<N, FN, IN>(start: &N, mut successors: FN) -> HashMap<N, usize>
+where
+    N: Clone + Eq + Hash,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    let mut distances = HashMap::new();
+    distances.insert(start.clone(), 0);
+    let mut frontier = vec![start.clone()];
+    let mut distance = 0;
+    while !frontier.is_empty() {
+        distance += 1;
+        let mut next_frontier = Vec::new();
+        for node in frontier.iter() {
+            for next in successors(node) {
+                if !distances.contains_key(&next) {
+                    distances.insert(next.clone(), distance);
+                    next_frontier.push(next);
+                }
+            }
+        }
+        frontier = next_frontier;
+    }
+    distances
+}
+
+/// Cheapest path from `start` to the first node satisfying `success`, and its cost.
+pub fn dijkstra<N, C, FN, IN, FS>(start: &N, successors: FN, success: FS) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FS: FnMut(&N) -> bool,
+{
+    astar(start, successors, |_| C::zero(), success)
+}
+
+/// Like `dijkstra`, exploring first the nodes with the lowest cost plus `heuristic`. The result is
+/// the cheapest path as long as `heuristic` never overestimates the remaining cost.
+pub fn astar<N, C, FN, IN, FH, FS>(
+    start: &N,
+    mut successors: FN,
+    mut heuristic: FH,
+    mut success: FS,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FH: FnMut(&N) -> C,
+    FS: FnMut(&N) -> bool,
+{
+    let mut nodes = vec![start.clone()];
+    let mut parents = vec![usize::MAX];
+    let mut costs = vec![C::zero()];
+    let mut indices = HashMap::new();
+    indices.insert(start.clone(), 0);
+
+    let mut queue = BinaryHeap::new();
+    queue.push(Visit {
+        estimate: heuristic(start),
+        cost: C::zero(),
+        index: 0,
+    });
+
+    while let Some(Visit { cost, index, .. }) = queue.pop() {
+        if cost > costs[index] {
+            // A cheaper way to this node was found after this visit was queued
+            continue;
+        }
+        if success(&nodes[index]) {
+            return Some((reconstruct(&nodes, &parents, index), cost));
+        }
+        for (next, step) in successors(&nodes[index]) {
+            let next_cost = cost + step;
+            let next_index = match indices.get(&next) {
+                Some(&i) if costs[i] <= next_cost => continue,
+                Some(&i) => {
+                    parents[i] = index;
+                    costs[i] = next_cost;
+                    i
+                }
+                None => {
+                    indices.insert(next.clone(), nodes.len());
+                    nodes.push(next);
+                    parents.push(index);
+                    costs.push(next_cost);
+                    nodes.len() - 1
+                }
+            };
+            queue.push(Visit {
+                estimate: next_cost + heuristic(&nodes[next_index]),
+                cost: next_cost,
+                index: next_index,
+            });
+        }
+    }
+    None
+}
+
+fn reconstruct<N: Clone>(nodes: &[N], parents: &[usize], end: usize) -> Vec<N> {
+    let mut path = vec![nodes[end].clone()];
+    let mut current = end;
+    while parents[current] != usize::MAX {
+        current = parents[current];
+        path.push(nodes[current].clone());
+    }
+    path.reverse();
+    path
+}
+
+/// Entry of the A* queue, ordered so that `BinaryHeap` pops the lowest estimate first.
+struct Visit<C> {
+    estimate: C,
+    cost: C,
+    index: usize,
+}
+
+impl<C: Ord> PartialEq for Visit<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<C: Ord> Eq for Visit<C> {}
+
+impl<C: Ord> PartialOrd for Visit<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<C: Ord> Ord for Visit<C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Reversed for a min-heap; on ties prefer the node furthest along
+        other
+            .estimate
+            .cmp(&self.estimate)
+            .then_with(|| self.cost.cmp(&other.cost))
+    }
+}
+
+#[cfg(test)]
+use crate::geometry::Point;
+#[cfg(test)]
+use crate::grid::Grid;
+
+#[cfg(test)]
+struct Maze(Grid<char>);
+
+#[cfg(test)]
+impl Graph for Maze {
+    type Node = Point<usize>;
+
+    fn neighbours(&self, p: &Point<usize>) -> Vec<Point<usize>> {
+        self.0
+            .neighbours4(*p)
+            .filter(|&n| self.0[n] != '#')
+            .collect()
+    }
+}
+
+#[cfg(test)]
+impl WeightedGraph for Maze {
+    type Node = Point<usize>;
+    type Cost = u32;
+
+    /// Entering a digit costs its value, any other open cell costs 1.
+    fn edges(&self, p: &Point<usize>) -> Vec<(Point<usize>, u32)> {
+        Graph::neighbours(self, p)
+            .into_iter()
+            .map(|n| (n, self.0[n].to_digit(10).unwrap_or(1)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+fn test_maze() -> Maze {
+    let maze = [
+        "#########",
+        "#S..#...#",
+        "#.#.#.#.#",
+        "#.#9..#.#",
+        "#.#####.#",
+        "#...1..E#",
+        "#########",
+    ];
+    Maze(maze.join("\n").parse().unwrap())
+}
+
+#[test]
+fn test_search_bfs_and_flood_fill() {
+    use pathfinding::directed::bfs as pf;
+
+    let maze = test_maze();
+    let start = maze.0.find(&'S').unwrap();
+    let end = maze.0.find(&'E').unwrap();
+    let path = maze.bfs(&start, |&p| p == end).unwrap();
+    let expected = pf::bfs(&start, |p| Graph::neighbours(&maze, p), |&p| p == end).unwrap();
+    assert_eq!(path.len(), expected.len());
+    assert_eq!((path[0], path[path.len() - 1]), (start, end));
+    assert!(path
+        .windows(2)
+        .all(|w| w[0].signed().manhattan(w[1].signed()) == 1));
+
+    let distances = maze.distances(&start);
+    let open = maze.0.values().filter(|&&c| c != '#').count();
+    assert_eq!(distances.len(), open);
+    assert_eq!(distances[&end], path.len() - 1);
+    for (p, &distance) in distances.iter() {
+        let expected = pf::bfs(&start, |q| Graph::neighbours(&maze, q), |q| q == p).unwrap();
+        assert_eq!(distance, expected.len() - 1);
+    }
+    assert_eq!(maze.bfs(&start, |_| false), None);
+}
+
+#[test]
+fn test_search_dijkstra_and_astar() {
+    use pathfinding::directed::{astar as pf_astar, dijkstra as pf_dijkstra};
+
+    let maze = test_maze();
+    let start = maze.0.find(&'S').unwrap();
+    let heuristic = |p: &Point<usize>| p.signed().manhattan(Point::new(7, 5)) as u32;
+    for goal in maze.0.points().filter(|&p| maze.0[p] != '#') {
+        let expected =
+            pf_dijkstra::dijkstra(&start, |p| maze.edges(p), |&p| p == goal).map(|(_, c)| c);
+        let (path, cost) = maze.dijkstra(&start, |&p| p == goal).unwrap();
+        assert_eq!(Some(cost), expected);
+        let path_cost: u32 = path[1..]
+            .iter()
+            .map(|&p| maze.0[p].to_digit(10).unwrap_or(1))
+            .sum();
+        assert_eq!(path_cost, cost);
+        assert_eq!(path.last(), Some(&goal));
+    }
+
+    let end = maze.0.find(&'E').unwrap();
+    let expected = pf_astar::astar(&start, |p| maze.edges(p), heuristic, |&p| p == end);
+    let found = maze.astar(&start, heuristic, |&p| p == end).map(|(_, c)| c);
+    assert_eq!(found, expected.map(|(_, c)| c));
+    // The 9 makes the upper corridor dearer than 4 steps down and 6 right
+    assert_eq!(found, Some(10));
+}
@@ -0,0 +1,246 @@
+use crate::bench::{BenchConfig, DayBench, Stage};
+use crate::render::Recorder;
+use crate::tui::Session;
+use crate::{AdventError, AdventResult};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::fmt;
+use std::marker::PhantomData;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+pub type Auxiliary = Map<String, Value>;
+
+/// Puzzle constants given in the puzzle text rather than in the input file, like day 8's image
+/// size. They default to the puzzle's values and are overridden with `--param key=value`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params(HashMap<String, String>);
+
+impl Params {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert<K: Into<String>, V: Into<String>>(&mut self, key: K, value: V) {
+        self.0.insert(key.into(), value.into());
+    }
+
+    /// Adds a `key=value` pair.
+    pub fn insert_pair(&mut self, pair: &str) -> AdventResult<()> {
+        let (key, value) = pair
+            .split_once('=')
+            .ok_or_else(|| AdventError::InvalidParam(pair.to_string()))?;
+        self.insert(key.trim(), value.trim());
+        Ok(())
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.0.keys().map(String::as_str)
+    }
+
+    pub fn get_str(&self, key: &str) -> Option<&str> {
+        self.0.get(key).map(String::as_str)
+    }
+
+    /// The value of `key`, `default` when it was not given.
+    pub fn get<T: FromStr>(&self, key: &str, default: T) -> AdventResult<T> {
+        match self.0.get(key) {
+            Some(value) => value
+                .parse()
+                .map_err(|_| AdventError::InvalidParam(key.to_string())),
+            None => Ok(default),
+        }
+    }
+}
+
+/// A day of the calendar: parses its puzzle input once and solves both parts from it.
+pub trait Solution {
+    const DAY: u8;
+    type Input;
+    type Part1: fmt::Display;
+    type Part2: fmt::Display;
+
+    fn parse(input: &str) -> AdventResult<Self::Input>;
+    fn part1(input: &Self::Input) -> AdventResult<Self::Part1>;
+    fn part2(input: &Self::Input) -> AdventResult<Self::Part2>;
+
+    /// Names of the parameters read by `parse_with`.
+    const PARAMS: &'static [&'static str] = &[];
+
+    /// `parse` for days whose input depends on `Params`; they implement `parse` as `parse_with`
+    /// the default parameters.
+    fn parse_with(input: &str, _params: &Params) -> AdventResult<Self::Input> {
+        Self::parse(input)
+    }
+
+    /// Intermediate results worth reporting next to the answers, e.g. in the JSON output.
+    fn auxiliary(_input: &Self::Input) -> AdventResult<Auxiliary> {
+        Ok(Auxiliary::new())
+    }
+
+    /// Replays the day's simulation into `recorder`. Days without one record nothing.
+    fn record(_input: &Self::Input, _recorder: &mut Recorder) -> AdventResult<()> {
+        Ok(())
+    }
+
+    /// Interactive session to play the day's program by hand, for days that have one.
+    fn console(_input: &Self::Input) -> Option<Session> {
+        None
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: &'static [Part] = &[Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answer {
+    pub day: u8,
+    pub part: Part,
+    pub value: String,
+    pub time: Duration,
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Pictures and other multi-line answers start on their own line
+        let separator = if self.value.contains('\n') { "\n" } else { " " };
+        write!(
+            f,
+            "Day {:02} - Part {}:{}{}",
+            self.day, self.part, separator, self.value
+        )
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DayReport {
+    pub day: u8,
+    pub parse_time: Duration,
+    pub answers: Vec<Answer>,
+}
+
+/// Object-safe view of a `Solution` so days can be selected at runtime.
+pub trait Runner {
+    fn day(&self) -> u8;
+    fn run(&self, input: &str, parts: &[Part]) -> AdventResult<DayReport>;
+    fn auxiliary(&self, input: &str) -> AdventResult<Auxiliary>;
+    fn record(&self, input: &str, recorder: &mut Recorder) -> AdventResult<()>;
+    fn console(&self, input: &str) -> AdventResult<Option<Session>>;
+    fn bench(&self, input: &str, parts: &[Part], config: &BenchConfig) -> AdventResult<DayBench>;
+}
+
+pub struct SolutionRunner<S> {
+    params: Params,
+    solution: PhantomData<S>,
+}
+
+impl<S: Solution> SolutionRunner<S> {
+    pub fn new() -> Self {
+        SolutionRunner {
+            params: Params::new(),
+            solution: PhantomData,
+        }
+    }
+
+    /// Fails on parameters the day does not read.
+    pub fn with_params(params: Params) -> AdventResult<Self> {
+        if let Some(key) = params.keys().find(|key| !S::PARAMS.contains(key)) {
+            return Err(AdventError::UnknownParam(key.to_string()));
+        }
+        Ok(SolutionRunner {
+            params,
+            solution: PhantomData,
+        })
+    }
+
+    fn parse(&self, input: &str) -> AdventResult<S::Input> {
+        S::parse_with(input, &self.params)
+    }
+}
+
+impl<S: Solution> Default for SolutionRunner<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S: Solution> Runner for SolutionRunner<S> {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn run(&self, input: &str, parts: &[Part]) -> AdventResult<DayReport> {
+        let start = Instant::now();
+        let input = self.parse(input)?;
+        let parse_time = start.elapsed();
+        let answers = parts
+            .iter()
+            .map(|&part| {
+                let start = Instant::now();
+                let value = match part {
+                    Part::One => S::part1(&input)?.to_string(),
+                    Part::Two => S::part2(&input)?.to_string(),
+                };
+                Ok(Answer {
+                    day: S::DAY,
+                    part,
+                    value,
+                    time: start.elapsed(),
+                })
+            })
+            .collect::<AdventResult<_>>()?;
+        Ok(DayReport {
+            day: S::DAY,
+            parse_time,
+            answers,
+        })
+    }
+
+    fn auxiliary(&self, input: &str) -> AdventResult<Auxiliary> {
+        S::auxiliary(&self.parse(input)?)
+    }
+
+    fn record(&self, input: &str, recorder: &mut Recorder) -> AdventResult<()> {
+        S::record(&self.parse(input)?, recorder)
+    }
+
+    fn console(&self, input: &str) -> AdventResult<Option<Session>> {
+        Ok(S::console(&self.parse(input)?))
+    }
+
+    fn bench(&self, input: &str, parts: &[Part], config: &BenchConfig) -> AdventResult<DayBench> {
+        let mut stages = vec![(Stage::Parse, config.measure(|| self.parse(input))?)];
+        let input = self.parse(input)?;
+        for &part in parts {
+            let stats = match part {
+                Part::One => config.measure(|| S::part1(&input))?,
+                Part::Two => config.measure(|| S::part2(&input))?,
+            };
+            stages.push((Stage::Part(part), stats));
+        }
+        Ok(DayBench {
+            day: S::DAY,
+            stages,
+        })
+    }
+}
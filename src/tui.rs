@@ -0,0 +1,288 @@
+//! Terminal front-end to play the interactive Intcode programs by hand: day 13's arcade cabinet
+//! with the arrow keys, and the ASCII programs of days 17, 21 and 25 by typing commands.
+//!
+//! A `Session` pairs the VM with a `Console`, which draws the program's output and turns key
+//! presses into program input. `run` drives a session from the terminal until Esc or Ctrl-C.
+use crate::geometry::Point;
+use crate::grid::SparseGrid;
+use crate::intcode::IntCode;
+use crate::render;
+use crate::AdventResult;
+use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use crossterm::style::Print;
+use crossterm::{cursor, execute, queue, terminal};
+use std::io::{self, Write};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Key {
+    Char(char),
+    Enter,
+    Backspace,
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+/// How a program's output is shown and how keys become its input.
+pub trait Console {
+    /// Takes in everything the program printed since the last call.
+    fn display(&mut self, output: &[isize]);
+    /// Screen contents, one line per row.
+    fn screen(&self) -> String;
+    /// Line shown under the screen, e.g. the command being typed.
+    fn prompt(&self) -> String;
+    /// Program input for `key`, `None` while more keys are needed.
+    fn key(&mut self, key: Key) -> Option<Vec<isize>>;
+}
+
+/// Day 13's cabinet: `x, y, tile` triples, `-1, 0, score` for the score, and a joystick.
+#[derive(Default)]
+pub struct Arcade {
+    screen: SparseGrid<isize>,
+    score: isize,
+    pending: Vec<isize>,
+}
+
+impl Arcade {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Console for Arcade {
+    fn display(&mut self, output: &[isize]) {
+        self.pending.extend_from_slice(output);
+        let complete = self.pending.len() - self.pending.len() % 3;
+        for tile in self.pending.drain(..complete).collect::<Vec<_>>().chunks(3) {
+            if tile[0] == -1 && tile[1] == 0 {
+                self.score = tile[2];
+            } else {
+                self.screen.insert(Point::new(tile[0], tile[1]), tile[2]);
+            }
+        }
+    }
+
+    fn screen(&self) -> String {
+        let (screen, _) = self.screen.to_grid(0);
+        render::text(&screen, |&tile| match tile {
+            1 => '#',
+            2 => '=',
+            3 => '_',
+            4 => 'o',
+            _ => ' ',
+        })
+    }
+
+    fn prompt(&self) -> String {
+        format!("Score: {}   [←/→] move  [space] stay", self.score)
+    }
+
+    fn key(&mut self, key: Key) -> Option<Vec<isize>> {
+        match key {
+            Key::Left | Key::Char('a') => Some(vec![-1]),
+            Key::Right | Key::Char('d') => Some(vec![1]),
+            Key::Down | Key::Char(' ') | Key::Char('s') => Some(vec![0]),
+            _ => None,
+        }
+    }
+}
+
+/// Programs talking in ASCII. Values past the ASCII range, usually the answer, are shown as
+/// numbers on their own line.
+#[derive(Default)]
+pub struct AsciiTerminal {
+    text: String,
+    line: String,
+}
+
+impl AsciiTerminal {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Console for AsciiTerminal {
+    fn display(&mut self, output: &[isize]) {
+        for &value in output {
+            match value {
+                0..=127 => self.text.push(value as u8 as char),
+                _ => self.text.push_str(&format!("\n{}\n", value)),
+            }
+        }
+    }
+
+    fn screen(&self) -> String {
+        self.text.clone()
+    }
+
+    fn prompt(&self) -> String {
+        format!("> {}", self.line)
+    }
+
+    fn key(&mut self, key: Key) -> Option<Vec<isize>> {
+        match key {
+            Key::Char(c) if c.is_ascii() && !c.is_ascii_control() => self.line.push(c),
+            Key::Backspace => {
+                self.line.pop();
+            }
+            Key::Enter => {
+                // The programs don't echo their input, keep the transcript readable
+                self.text.push_str(&self.line);
+                self.text.push('\n');
+                let mut input: Vec<isize> = self.line.bytes().map(isize::from).collect();
+                input.push(10);
+                self.line.clear();
+                return Some(input);
+            }
+            _ => (),
+        }
+        None
+    }
+}
+
+/// A running program and the console it is played on.
+pub struct Session {
+    intcode: IntCode,
+    console: Box<dyn Console>,
+}
+
+impl Session {
+    /// Starts `program`, running it until it first waits for input.
+    pub fn new<C: Console + 'static>(program: &[isize], console: C) -> Self {
+        let mut session = Self {
+            intcode: IntCode::new(program),
+            console: Box::new(console),
+        };
+        session.resume(&[]);
+        session
+    }
+
+    fn resume(&mut self, input: &[isize]) {
+        let output = self.intcode.run_till_blocked(input);
+        self.console.display(&output);
+    }
+
+    /// Forwards `key` to the console and feeds the program whatever input it produces.
+    pub fn key(&mut self, key: Key) {
+        if self.intcode.has_halted() {
+            return;
+        }
+        if let Some(input) = self.console.key(key) {
+            self.resume(&input);
+        }
+    }
+
+    pub fn has_halted(&self) -> bool {
+        self.intcode.has_halted()
+    }
+
+    pub fn screen(&self) -> String {
+        self.console.screen()
+    }
+
+    pub fn prompt(&self) -> String {
+        self.console.prompt()
+    }
+
+    pub fn status(&self) -> String {
+        let state = if self.intcode.has_halted() {
+            "halted"
+        } else {
+            "waiting for input"
+        };
+        format!(
+            "{} instructions | {}",
+            self.intcode.instruction_count(),
+            state
+        )
+    }
+}
+
+/// Plays `session` in the terminal's alternate screen until Esc or Ctrl-C.
+pub fn run(session: &mut Session) -> AdventResult<()> {
+    let mut stdout = io::stdout();
+    terminal::enable_raw_mode()?;
+    execute!(stdout, terminal::EnterAlternateScreen, cursor::Hide)?;
+    let result = event_loop(session, &mut stdout);
+    execute!(stdout, cursor::Show, terminal::LeaveAlternateScreen)?;
+    terminal::disable_raw_mode()?;
+    result
+}
+
+fn event_loop<W: Write>(session: &mut Session, out: &mut W) -> AdventResult<()> {
+    loop {
+        draw(session, out)?;
+        let key = match event::read()? {
+            Event::Key(key) if key.kind != KeyEventKind::Release => key,
+            _ => continue,
+        };
+        let key = match key.code {
+            KeyCode::Esc => return Ok(()),
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => return Ok(()),
+            KeyCode::Char(c) => Key::Char(c),
+            KeyCode::Enter => Key::Enter,
+            KeyCode::Backspace => Key::Backspace,
+            KeyCode::Left => Key::Left,
+            KeyCode::Right => Key::Right,
+            KeyCode::Up => Key::Up,
+            KeyCode::Down => Key::Down,
+            _ => continue,
+        };
+        session.key(key);
+    }
+}
+
+/// Draws the end of the screen that fits above the separator, status and prompt lines.
+fn draw<W: Write>(session: &Session, out: &mut W) -> AdventResult<()> {
+    let (width, height) = terminal::size()?;
+    let (width, height) = (width as usize, height as usize);
+    let screen = session.screen();
+    let lines: Vec<&str> = screen.lines().collect();
+    let visible = height.saturating_sub(3);
+    let lines = &lines[lines.len().saturating_sub(visible)..];
+
+    queue!(out, terminal::Clear(terminal::ClearType::All))?;
+    let footer = [
+        "-".repeat(width),
+        format!("{} | [esc] quit", session.status()),
+        session.prompt(),
+    ];
+    let rows = lines.iter().map(|line| line.to_string()).chain(footer);
+    for (y, row) in rows.enumerate() {
+        let row: String = row.chars().take(width).collect();
+        queue!(out, cursor::MoveTo(0, y as u16), Print(row))?;
+    }
+    out.flush()?;
+    Ok(())
+}
+
+#[test]
+fn test_tui_ascii_session() {
+    // Prints "Hi", reads a value and prints it back
+    let program = [104, 72, 104, 105, 104, 10, 3, 50, 4, 50, 99];
+    let mut session = Session::new(&program, AsciiTerminal::new());
+    assert_eq!(session.screen(), "Hi\n");
+    assert_eq!(session.status(), "3 instructions | waiting for input");
+
+    session.key(Key::Char('x'));
+    session.key(Key::Char('y'));
+    session.key(Key::Backspace);
+    assert_eq!(session.prompt(), "> x");
+    session.key(Key::Enter);
+    assert_eq!(session.screen(), "Hi\nx\nx");
+    assert_eq!(session.status(), "6 instructions | halted");
+    assert!(session.has_halted());
+}
+
+#[test]
+fn test_tui_arcade() {
+    let mut arcade = Arcade::new();
+    arcade.display(&[0, 0, 1, 1, 0, 1, 2, 0]);
+    arcade.display(&[1, 1, 1, 4, -1, 0, 42]);
+    assert_eq!(arcade.screen(), "###\n o ");
+    assert!(arcade.prompt().starts_with("Score: 42"));
+    assert_eq!(arcade.key(Key::Left), Some(vec![-1]));
+    assert_eq!(arcade.key(Key::Char(' ')), Some(vec![0]));
+    assert_eq!(arcade.key(Key::Enter), None);
+}
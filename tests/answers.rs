@@ -0,0 +1,91 @@
+//! Pins every day's answers on the cached puzzle inputs to `tests/answers.csv`.
+//! Days whose `input/dayNN.txt` is missing are skipped rather than downloaded.
+use advent2019::solution::Part;
+use advent2019::{days, file_name};
+use std::fs;
+
+const ANSWERS: &str = include_str!("answers.csv");
+
+fn expected_answers(day: u8) -> Vec<(Part, String)> {
+    let mut reader = csv::Reader::from_reader(ANSWERS.as_bytes());
+    reader
+        .records()
+        .map(|r| r.expect("answers.csv record"))
+        .filter(|r| r[0].parse::<u8>().expect("day") == day)
+        .map(|r| {
+            let part = match &r[1] {
+                "1" => Part::One,
+                "2" => Part::Two,
+                p => panic!("Unexpected part {}", p),
+            };
+            (part, r[2].to_string())
+        })
+        .collect()
+}
+
+fn check_day(day: u8) {
+    let input = match fs::read_to_string(file_name(day)) {
+        Ok(input) => input,
+        Err(_) => {
+            println!("Skipping day {:02}: no cached input", day);
+            return;
+        }
+    };
+
+    let expected = expected_answers(day);
+    assert!(!expected.is_empty(), "No expected answers for day {}", day);
+    let parts: Vec<Part> = expected.iter().map(|(part, _)| *part).collect();
+
+    let runner = days::runner(day).expect("solution");
+    let report = runner.run(&input, &parts).expect("answers");
+    for (answer, (part, value)) in report.answers.iter().zip(expected.iter()) {
+        assert_eq!(answer.part, *part);
+        assert_eq!(&answer.value, value, "Day {} part {}", day, part);
+    }
+}
+
+macro_rules! answer_tests {
+    ($($name:ident => $day:literal),* $(,)?) => {
+        $(
+            #[test]
+            fn $name() {
+                check_day($day)
+            }
+        )*
+    };
+}
+
+answer_tests! {
+    day01 => 1,
+    day02 => 2,
+    day03 => 3,
+    day04 => 4,
+    day05 => 5,
+    day06 => 6,
+    day07 => 7,
+    day08 => 8,
+    day09 => 9,
+    day10 => 10,
+    day11 => 11,
+    day12 => 12,
+    day13 => 13,
+    day14 => 14,
+    day15 => 15,
+    day16 => 16,
+    day17 => 17,
+    day18 => 18,
+    day19 => 19,
+    day20 => 20,
+    day21 => 21,
+    day22 => 22,
+    day23 => 23,
+    day24 => 24,
+    day25 => 25,
+}
+
+#[test]
+fn every_day_has_answers() {
+    for &day in days::ALL {
+        assert!(!expected_answers(day).is_empty(), "Day {}", day);
+    }
+}
@@ -0,0 +1,148 @@
+//! Runs every puzzle example under `tests/examples/` through its day's `Solution`.
+//!
+//! Examples live in `tests/examples/dayNN/<name>.txt`: a header, a `---` line, then the input
+//! exactly as the day would read it from its input file.
+//!
+//! ```text
+//! # Second larger example            <- comments are ignored
+//! part1: 135                         <- expected answers, either part can be left out
+//! part2: 410
+//! param: steps=10                    <- `--param` for days whose constants differ in examples
+//! ---
+//! R98,U47,R26,D63,R33,U87,L62,D20,R33,U53,R51
+//! U98,R91,D20,R16,D67,R40,U7,R15,U6,R7
+//! ```
+//!
+//! Adding a file is enough to add a test. `cargo test --test examples -- day14` only runs the
+//! examples whose path contains `day14`.
+use advent2019::days;
+use advent2019::solution::{Params, Part};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+struct Example {
+    day: u8,
+    answers: Vec<(Part, String)>,
+    params: Params,
+    input: String,
+}
+
+impl Example {
+    fn load(day: u8, path: &Path) -> Result<Example, String> {
+        let text = fs::read_to_string(path).map_err(|err| err.to_string())?;
+        let (header, input) = text
+            .split_once("---\n")
+            .ok_or("missing `---` line between header and input")?;
+
+        let mut answers = Vec::new();
+        let mut params = Params::new();
+        for line in header.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let part = match line.split_once(':') {
+                Some(("part1", answer)) => (Part::One, answer.trim().to_string()),
+                Some(("part2", answer)) => (Part::Two, answer.trim().to_string()),
+                Some(("param", pair)) => {
+                    params.insert_pair(pair).map_err(|err| err.to_string())?;
+                    continue;
+                }
+                _ => return Err(format!("unexpected header line `{}`", line)),
+            };
+            answers.push(part);
+        }
+        if answers.is_empty() {
+            return Err("no expected answer".to_string());
+        }
+
+        Ok(Example {
+            day,
+            answers,
+            params,
+            input: input.to_string(),
+        })
+    }
+
+    fn check(&self) -> Result<(), String> {
+        let runner =
+            days::runner_with_params(self.day, &self.params).map_err(|err| err.to_string())?;
+        let parts: Vec<Part> = self.answers.iter().map(|(part, _)| *part).collect();
+        let report = runner
+            .run(&self.input, &parts)
+            .map_err(|err| err.to_string())?;
+        for (answer, (part, expected)) in report.answers.iter().zip(self.answers.iter()) {
+            if &answer.value != expected {
+                return Err(format!(
+                    "part {}: expected {}, got {}",
+                    part, expected, answer.value
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Every `dayNN/<name>.txt` file, sorted by path.
+fn example_files(root: &Path) -> Vec<(String, u8, PathBuf)> {
+    let mut files = Vec::new();
+    let days = fs::read_dir(root).expect("tests/examples directory");
+    for dir in days.map(|entry| entry.expect("directory entry").path()) {
+        let dir_name = dir.file_name().unwrap().to_string_lossy().to_string();
+        let day: u8 = match dir_name.strip_prefix("day").map(str::parse) {
+            Some(Ok(day)) => day,
+            _ => continue,
+        };
+        for file in fs::read_dir(&dir).expect("day directory") {
+            let path = file.expect("directory entry").path();
+            if path.extension().is_some_and(|ext| ext == "txt") {
+                let stem = path.file_stem().unwrap().to_string_lossy().to_string();
+                files.push((format!("{}/{}", dir_name, stem), day, path));
+            }
+        }
+    }
+    files.sort();
+    files
+}
+
+fn main() -> ExitCode {
+    // libtest style: the first argument that is not a flag filters by name
+    let filter = env::args().skip(1).find(|arg| !arg.starts_with('-'));
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/examples");
+    let files: Vec<_> = example_files(&root)
+        .into_iter()
+        .filter(|(name, _, _)| filter.as_ref().is_none_or(|f| name.contains(f.as_str())))
+        .collect();
+
+    println!("\nrunning {} examples", files.len());
+    let mut failures = Vec::new();
+    for (name, day, path) in files.iter() {
+        match Example::load(*day, path).and_then(|example| example.check()) {
+            Ok(()) => println!("example {} ... ok", name),
+            Err(err) => {
+                println!("example {} ... FAILED", name);
+                failures.push(format!("{}: {}", name, err));
+            }
+        }
+    }
+
+    if !failures.is_empty() {
+        println!("\nfailures:");
+        failures
+            .iter()
+            .for_each(|failure| println!("    {}", failure));
+    }
+    let result = if failures.is_empty() { "ok" } else { "FAILED" };
+    println!(
+        "\nexample result: {}. {} passed; {} failed\n",
+        result,
+        files.len() - failures.len(),
+        failures.len()
+    );
+    if failures.is_empty() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}